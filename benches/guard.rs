@@ -0,0 +1,46 @@
+// benches/guard.rs
+//
+// Cost of one fire (trigger, wait, commit) with a guard that holds its own reference to the
+// shared state (`ready`) versus one that borrows the handle (`ready_ref`).
+// Run with `cargo bench --bench guard`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use tokio::time::Duration;
+use tokio_debouncer::{DebounceMode, Debouncer};
+
+const FIRES: u32 = 1_000_000;
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    runtime.block_on(async {
+        let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Leading);
+        // Warm up both paths
+        for _ in 0..FIRES / 10 {
+            debounce.trigger();
+            drop(debounce.ready().await);
+            debounce.trigger();
+            drop(debounce.ready_ref().await);
+        }
+
+        let start = Instant::now();
+        for _ in 0..FIRES {
+            debounce.trigger();
+            black_box(debounce.ready().await);
+        }
+        report("ready (shared Arc)", start);
+
+        let start = Instant::now();
+        for _ in 0..FIRES {
+            debounce.trigger();
+            black_box(debounce.ready_ref().await);
+        }
+        report("ready_ref (borrowed)", start);
+    });
+}
+
+fn report(name: &str, start: Instant) {
+    let per_fire = start.elapsed() / FIRES;
+    println!("{name:<22} {per_fire:>8?} per fire");
+}
@@ -0,0 +1,254 @@
+//! Builder for debouncers with more than a cooldown and a mode.
+
+use std::fmt;
+use std::sync::Arc;
+use tokio::time::Duration;
+
+use crate::clock::default_clock;
+use crate::{Backoff, Clock, DebounceMode, Debouncer, Priority};
+
+/// Builder for [`Debouncer`] configuration.
+///
+/// Defaults to a 100ms cooldown in trailing mode without a max-wait cap.
+///
+/// ```rust
+/// use tokio_debouncer::{DebounceMode, DebouncerBuilder};
+/// use tokio::time::Duration;
+///
+/// let debouncer = DebouncerBuilder::new()
+///     .cooldown(Duration::from_millis(500))
+///     .mode(DebounceMode::Trailing)
+///     .max_wait(Duration::from_secs(2))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct DebouncerBuilder {
+    pub(crate) cooldown: Duration,
+    pub(crate) mode: DebounceMode,
+    pub(crate) max_wait: Option<Duration>,
+    pub(crate) max_batch: Option<usize>,
+    pub(crate) backoff: Option<Backoff>,
+    pub(crate) strict_leading: bool,
+    pub(crate) dedup_window: Duration,
+    pub(crate) cooldown_from_trigger: bool,
+    pub(crate) gap_after_processing: bool,
+    pub(crate) fire_on_last_drop: bool,
+    /// Close as part of handing out the first guard; used by `OneShotDebouncer`.
+    pub(crate) close_on_claim: bool,
+    pub(crate) priority_cooldowns: [Option<Duration>; 3],
+    pub(crate) jitter: Duration,
+    pub(crate) jitter_seed: Option<u64>,
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+impl fmt::Debug for DebouncerBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebouncerBuilder")
+            .field("cooldown", &self.cooldown)
+            .field("mode", &self.mode)
+            .field("max_wait", &self.max_wait)
+            .field("max_batch", &self.max_batch)
+            .field("backoff", &self.backoff)
+            .field("strict_leading", &self.strict_leading)
+            .field("dedup_window", &self.dedup_window)
+            .field("cooldown_from_trigger", &self.cooldown_from_trigger)
+            .field("gap_after_processing", &self.gap_after_processing)
+            .field("fire_on_last_drop", &self.fire_on_last_drop)
+            .field("priority_cooldowns", &self.priority_cooldowns)
+            .field("jitter", &self.jitter)
+            .field("jitter_seed", &self.jitter_seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for DebouncerBuilder {
+    fn default() -> Self {
+        Self {
+            cooldown: Debouncer::DEFAULT_COOLDOWN,
+            mode: DebounceMode::default(),
+            max_wait: None,
+            max_batch: None,
+            backoff: None,
+            strict_leading: false,
+            dedup_window: Duration::ZERO,
+            cooldown_from_trigger: false,
+            gap_after_processing: false,
+            fire_on_last_drop: false,
+            close_on_claim: false,
+            priority_cooldowns: [None; 3],
+            jitter: Duration::ZERO,
+            jitter_seed: None,
+            clock: default_clock(),
+        }
+    }
+}
+
+impl DebouncerBuilder {
+    /// Create a builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cooldown, the minimum time between fires.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Set the debounce mode.
+    pub fn mode(mut self, mode: DebounceMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Cap how long a pending trigger can wait, even if triggers keep arriving.
+    /// Must not be shorter than the cooldown.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Fire as soon as `max_batch` triggers have been coalesced, whichever comes first
+    /// of this and the cooldown. Bounds batch size, e.g. for a value debouncer.
+    pub fn max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = Some(max_batch);
+        self
+    }
+
+    /// Grow the cooldown for retry-style workloads: each fire whose batch opened within the
+    /// cooldown of the previous fire multiplies the cooldown by `factor`, up to `max_cooldown`.
+    /// A batch opening after a full cooldown of quiet starts over from the base cooldown.
+    /// A `factor` of 0 or 1 never grows the cooldown.
+    pub fn backoff(mut self, factor: u32, max_cooldown: Duration) -> Self {
+        self.backoff = Some(Backoff {
+            factor: factor.max(1),
+            max_cooldown,
+        });
+        self
+    }
+
+    /// In Leading mode, discard triggers that arrive while the leading batch is being
+    /// processed or during the cooldown after it.
+    ///
+    /// By default such a trigger is kept and fires once the cooldown has elapsed. With strict leading only the first trigger of an idle period fires, so a
+    /// burst produces exactly one fire. Has no effect in other modes.
+    pub fn strict_leading(mut self, strict: bool) -> Self {
+        self.strict_leading = strict;
+        self
+    }
+
+    /// In Leading mode, discard triggers arriving within `window` of a leading fire.
+    ///
+    /// Near-simultaneous triggers collapse into that one fire, while triggers after the
+    /// window but within the cooldown are still kept and fire once the cooldown has elapsed.
+    /// A window covering the whole cooldown behaves like [`strict_leading`](Self::strict_leading).
+    /// Has no effect in other modes.
+    pub fn dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// In Leading mode, measure the cooldown from the most recent trigger instead of the
+    /// last fire.
+    ///
+    /// By default the next leading fire is allowed `cooldown` after the previous fire, however
+    /// many triggers arrive in between. With this flag every trigger during the cooldown
+    /// restarts it, as in Trailing mode, so a steady trickle of triggers keeps suppressing
+    /// fires until `cooldown` passes without one. The first trigger after such a quiet period
+    /// still fires immediately. Has no effect in other modes.
+    pub fn cooldown_from_trigger(mut self, enabled: bool) -> Self {
+        self.cooldown_from_trigger = enabled;
+        self
+    }
+
+    /// Guarantee at least `cooldown` between the end of one batch (its guard being dropped)
+    /// and the start of the next, whatever the mode.
+    ///
+    /// Without this flag Leading and Throttle keep a fixed cadence measured from when each
+    /// batch became eligible, and `max_wait`, priority triggers or `reschedule` can bring a
+    /// batch forward. With it, none of those can fire a batch sooner than `cooldown` after the
+    /// previous one finished processing; only [`flush`](Debouncer::flush) bypasses the gap.
+    /// This overrides the fixed cadence in every mode: Leading and Throttle windows then
+    /// start when processing ends, so slow batches stretch the interval between fires.
+    pub fn gap_after_processing(mut self, enabled: bool) -> Self {
+        self.gap_after_processing = enabled;
+        self
+    }
+
+    /// When the last handle is dropped with a batch still pending, run the
+    /// [`on_fire`](Debouncer::on_fire) callback for it instead of losing it silently.
+    ///
+    /// Only the callback runs, synchronously inside the drop; no task waiting in `ready()`
+    /// can observe the batch, since none is left. Outstanding guards, runners and spawned
+    /// helpers such as `pipe_to` keep the debouncer alive, so the final drop happens after them.
+    pub fn fire_on_last_drop(mut self, enabled: bool) -> Self {
+        self.fire_on_last_drop = enabled;
+        self
+    }
+
+    /// Let triggers of `priority` hurry the pending batch: it fires no later than `cooldown`
+    /// after a [`trigger_with_priority`](Debouncer::trigger_with_priority) call.
+    pub fn priority_cooldown(mut self, priority: Priority, cooldown: Duration) -> Self {
+        self.priority_cooldowns[priority as usize] = Some(cooldown);
+        self
+    }
+
+    /// Delay each batch's deadline by a random offset in `[0, jitter)`, so debouncers sharing
+    /// a cooldown don't all fire at once. Jitter only ever delays a fire, never advances it.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Seed the jitter generator for reproducible fire times (e.g. in tests).
+    /// Without a seed, each debouncer is seeded randomly.
+    pub fn jitter_seed(mut self, seed: u64) -> Self {
+        self.jitter_seed = Some(seed);
+        self
+    }
+
+    /// Read time from a custom [`Clock`] instead of `tokio::time`.
+    pub fn clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Validate the configuration and build the debouncer.
+    pub fn build(self) -> Result<Debouncer, BuildError> {
+        if let Some(max_wait) = self.max_wait {
+            if max_wait < self.cooldown {
+                return Err(BuildError::MaxWaitBelowCooldown {
+                    cooldown: self.cooldown,
+                    max_wait,
+                });
+            }
+        }
+        Ok(Debouncer::from_builder(self))
+    }
+}
+
+/// Error returned by [`DebouncerBuilder::build`] and
+/// [`QueueDebouncerBuilder::build`](crate::QueueDebouncerBuilder::build) for an invalid configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// The max-wait cap is shorter than the cooldown.
+    MaxWaitBelowCooldown { cooldown: Duration, max_wait: Duration },
+    /// A bounded queue was given a capacity of zero.
+    ZeroCapacity,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MaxWaitBelowCooldown { cooldown, max_wait } => write!(
+                f,
+                "max_wait ({:?}) must not be shorter than the cooldown ({:?})",
+                max_wait, cooldown
+            ),
+            BuildError::ZeroCapacity => write!(f, "queue capacity must be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
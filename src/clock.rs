@@ -0,0 +1,162 @@
+//! Time source abstraction.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::time::Instant;
+
+/// Source of time for a debouncer.
+///
+/// The default [`TokioClock`] uses `tokio::time`, which also honours Tokio's paused test clock.
+/// Implement this trait to drive a debouncer from a synthetic clock or another runtime's timer.
+///
+/// The clock is the only runtime coupling: wakeups use `tokio::sync::Notify` (or
+/// `event_listener::Event` with the `event-listener` feature), which works on any executor.
+pub trait Clock: Send + Sync + 'static {
+    /// The current instant.
+    fn now(&self) -> Instant;
+
+    /// Resolve once `now()` has reached `deadline`.
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+/// Clock backed by `tokio::time`.
+///
+/// This crate enables Tokio's `time` feature itself, so no extra Cargo feature is needed
+/// downstream. Waiting does need a Tokio runtime with its timer driver enabled: build
+/// custom runtimes with `enable_time()` or `enable_all()`, otherwise the first wait on a
+/// cooldown panics. `#[tokio::main]` and `#[tokio::test]` enable it already. Waiting
+/// outside any runtime panics too, with a message pointing at this clock.
+///
+/// Tokio's timer ticks once per millisecond ([`Debouncer::TIMER_RESOLUTION`]), so deadlines
+/// are rounded up to the next tick and a `from_micros(500)` cooldown waits about 1ms.
+///
+/// [`Debouncer::TIMER_RESOLUTION`]: crate::Debouncer::TIMER_RESOLUTION
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        if tokio::runtime::Handle::try_current().is_err() {
+            panic!(
+                "tokio-debouncer: waiting on a cooldown with TokioClock requires a Tokio runtime, \
+                 but none is running; enter one, or use another `Clock` to run without Tokio"
+            );
+        }
+        Box::pin(tokio::time::sleep_until(deadline))
+    }
+}
+
+/// Clock that follows wall-clock time (`SystemTime`) instead of the monotonic clock.
+///
+/// Use this when cooldowns should honour clock changes such as NTP adjustments: a forward
+/// jump brings pending deadlines closer. Wall-clock time is not monotonic, so a backward
+/// jump pushes deadlines further out and can delay fires by the size of the jump.
+///
+/// Sleeps are taken in slices of at most [`poll_interval`](Self::poll_interval) (one second
+/// by default), re-reading the wall clock after each, so a jump is noticed within one slice.
+pub struct WallClock<F = fn() -> SystemTime> {
+    source: F,
+    anchor: (Instant, SystemTime),
+    poll_interval: Duration,
+}
+
+impl WallClock {
+    /// Wall clock reading `SystemTime::now()`.
+    pub fn new() -> Self {
+        Self::with_source(SystemTime::now)
+    }
+}
+
+impl Default for WallClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Fn() -> SystemTime> WallClock<F> {
+    /// Wall clock reading the time from `source`, e.g. a mock in tests.
+    pub fn with_source(source: F) -> Self {
+        let anchor = (Instant::now(), source());
+        Self { source, anchor, poll_interval: Duration::from_secs(1) }
+    }
+
+    /// Longest single sleep before the wall clock is read again.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+impl<F> fmt::Debug for WallClock<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WallClock")
+            .field("anchor", &self.anchor)
+            .field("poll_interval", &self.poll_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fn() -> SystemTime + Send + Sync + 'static> Clock for WallClock<F> {
+    fn now(&self) -> Instant {
+        // Map wall time onto the Instant timeline relative to the anchor taken at creation
+        let (instant, system) = self.anchor;
+        match (self.source)().duration_since(system) {
+            Ok(ahead) => instant.checked_add(ahead).unwrap_or(instant),
+            Err(behind) => instant.checked_sub(behind.duration()).unwrap_or(instant),
+        }
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            loop {
+                let remaining = deadline.saturating_duration_since(self.now());
+                if remaining.is_zero() {
+                    return;
+                }
+                tokio::time::sleep(remaining.min(self.poll_interval)).await;
+            }
+        })
+    }
+}
+
+/// Clock backed by async-std's timer (requires the `async-std` feature).
+///
+/// Never picked implicitly: pass it to [`Debouncer::with_clock`](crate::Debouncer::with_clock)
+/// or [`DebouncerBuilder::clock`](crate::DebouncerBuilder::clock) to run under async-std.
+#[cfg(feature = "async-std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncStdClock;
+
+#[cfg(feature = "async-std")]
+impl Clock for AsyncStdClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async_std::task::sleep(deadline.saturating_duration_since(Instant::now())))
+    }
+}
+
+/// The clock used when none is configured: always [`TokioClock`], regardless of enabled
+/// features or of the runtime the debouncer happens to be built in.
+pub(crate) fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(TokioClock)
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        (**self).sleep_until(deadline)
+    }
+}
@@ -0,0 +1,195 @@
+//! Plain-data debouncer settings, e.g. for loading from a config file.
+
+use tokio::time::Duration;
+
+use crate::{BuildError, DebounceMode, Debouncer, DebouncerBuilder, MutexExt};
+
+/// Debouncer settings as plain data, with durations in milliseconds.
+///
+/// With the `serde` feature this can be deserialized from TOML, JSON, etc.
+/// and turned into a debouncer with [`Debouncer::from_config`].
+///
+/// ```rust
+/// use tokio_debouncer::{DebounceMode, Debouncer, DebouncerConfig};
+///
+/// let config = DebouncerConfig {
+///     cooldown_ms: 500,
+///     mode: DebounceMode::Trailing,
+///     max_wait_ms: Some(2000),
+/// };
+/// let debouncer = Debouncer::from_config(config).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebouncerConfig {
+    /// Minimum time between fires, in milliseconds.
+    pub cooldown_ms: u64,
+    /// The debounce mode.
+    pub mode: DebounceMode,
+    /// Optional max-wait cap, in milliseconds.
+    pub max_wait_ms: Option<u64>,
+}
+
+impl DebouncerConfig {
+    /// Settings with `cooldown_ms` and `mode` and no max-wait cap.
+    ///
+    /// This is a `const fn`, so settings can live in a `const` or `static`:
+    ///
+    /// ```rust
+    /// use tokio_debouncer::{DebounceMode, Debouncer, DebouncerConfig};
+    ///
+    /// const SAVE: DebouncerConfig = DebouncerConfig::new(500, DebounceMode::Trailing).max_wait_ms(2000);
+    /// let debouncer = Debouncer::from_config(SAVE).unwrap();
+    /// ```
+    pub const fn new(cooldown_ms: u64, mode: DebounceMode) -> Self {
+        Self {
+            cooldown_ms,
+            mode,
+            max_wait_ms: None,
+        }
+    }
+
+    /// Set the max-wait cap in milliseconds.
+    pub const fn max_wait_ms(mut self, max_wait_ms: u64) -> Self {
+        self.max_wait_ms = Some(max_wait_ms);
+        self
+    }
+}
+
+impl Default for DebouncerConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_ms: Debouncer::DEFAULT_COOLDOWN.as_millis() as u64,
+            mode: DebounceMode::default(),
+            max_wait_ms: None,
+        }
+    }
+}
+
+/// A debouncer's current cooldown, mode and max-wait cap, returned by [`Debouncer::config`].
+///
+/// Unlike [`DebouncerConfig`] it keeps full `Duration`s, so two debouncers compare equal
+/// only if their settings really are the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebouncerSettings {
+    /// Minimum time between fires.
+    pub cooldown: Duration,
+    /// The debounce mode.
+    pub mode: DebounceMode,
+    /// Optional max-wait cap.
+    pub max_wait: Option<Duration>,
+}
+
+impl DebouncerSettings {
+    /// A builder preset with these settings, e.g. to build an equivalent debouncer.
+    pub fn builder(self) -> DebouncerBuilder {
+        let builder = DebouncerBuilder::new().cooldown(self.cooldown).mode(self.mode);
+        match self.max_wait {
+            Some(max_wait) => builder.max_wait(max_wait),
+            None => builder,
+        }
+    }
+}
+
+/// A debouncer's live state as plain data, taken by [`Debouncer::snapshot`] and turned back
+/// into a debouncer by [`Debouncer::restore`], e.g. to resume debounce windows after a restart
+/// instead of firing a flood of batches.
+///
+/// `Instant`s are meaningless across processes, so times are stored relative to when the
+/// snapshot was taken, in milliseconds. Time spent between snapshot and restore is not
+/// counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebouncerSnapshot {
+    /// Whether a batch has fired before.
+    pub has_run: bool,
+    /// Whether a batch was pending.
+    pub triggered: bool,
+    /// Time since the start of the current cooldown window, in milliseconds.
+    pub since_last_run_ms: u64,
+}
+
+impl Debouncer {
+    /// Capture the state needed to resume this debouncer elsewhere, see [`DebouncerSnapshot`].
+    pub fn snapshot(&self) -> DebouncerSnapshot {
+        let state = self.inner.state.risky_lock();
+        let since_last_run = self.inner.clock.now().saturating_duration_since(state.last_run);
+        DebouncerSnapshot {
+            has_run: state.has_run,
+            triggered: state.triggered,
+            since_last_run_ms: as_millis(since_last_run),
+        }
+    }
+
+    /// Build a debouncer from `config` and resume the state in `snapshot`: the remaining
+    /// cooldown carries over, and a pending batch fires when it ends. A max-wait cap counts
+    /// from the restore.
+    pub fn restore(config: DebouncerConfig, snapshot: DebouncerSnapshot) -> Result<Self, BuildError> {
+        let debouncer = Self::from_config(config)?;
+        {
+            let mut state = debouncer.inner.state.risky_lock();
+            let now = debouncer.inner.clock.now();
+            // Beyond a full cooldown only "elapsed" matters, and a shorter offset is less
+            // likely to reach before the start of the monotonic clock
+            let since_last_run = Duration::from_millis(snapshot.since_last_run_ms).min(state.cooldown);
+            state.last_run = now.checked_sub(since_last_run).unwrap_or(now);
+            state.has_run = snapshot.has_run;
+            state.last_fired = if snapshot.has_run { Some(state.last_run) } else { None };
+            if snapshot.triggered {
+                state.triggered = true;
+                state.coalesced = 1;
+                state.first_trigger = Some(now);
+                state.jitter = debouncer.inner.draw_jitter(&mut state);
+            }
+        }
+        Ok(debouncer)
+    }
+
+    /// The current cooldown, mode and max-wait cap as one copyable value, e.g. for logging
+    /// or comparing debouncers generically.
+    ///
+    /// The cooldown is the configured base (see [`cooldown`](Self::cooldown)) and the mode
+    /// reflects any [`set_mode`](Self::set_mode). Durations are kept exact; use
+    /// [`DebouncerSettings::builder`] to build an equivalent debouncer.
+    pub fn config(&self) -> DebouncerSettings {
+        let state = self.inner.state.risky_lock();
+        DebouncerSettings {
+            cooldown: state.cooldown,
+            mode: state.mode,
+            max_wait: self.inner.max_wait,
+        }
+    }
+
+    /// Build a debouncer from plain-data settings, validating them like
+    /// [`DebouncerBuilder::build`](crate::DebouncerBuilder::build).
+    pub fn from_config(config: DebouncerConfig) -> Result<Self, BuildError> {
+        let mut builder = Self::builder()
+            .cooldown(Duration::from_millis(config.cooldown_ms))
+            .mode(config.mode);
+        if let Some(max_wait_ms) = config.max_wait_ms {
+            builder = builder.max_wait(Duration::from_millis(max_wait_ms));
+        }
+        builder.build()
+    }
+}
+
+/// Whole milliseconds in `duration`, saturating at `u64::MAX`.
+fn as_millis(duration: Duration) -> u64 {
+    duration.as_millis().min(u64::MAX as u128) as u64
+}
+
+/// Serde representation of a `Duration` as whole milliseconds, matching the `_ms` fields of
+/// [`DebouncerConfig`].
+#[cfg(feature = "serde")]
+pub(crate) mod duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use tokio::time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(super::as_millis(*duration))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
@@ -0,0 +1,86 @@
+//! Combined readiness over several independent debouncers.
+
+use std::future::{poll_fn, Future};
+use std::task::Poll;
+
+use crate::{Debouncer, DebouncerGuard};
+
+/// A fixed set of independent debouncers awaited together.
+///
+/// `ready()` resolves to the index and guard of whichever member becomes ready first,
+/// so a single `select!` branch can serve them all.
+///
+/// ```rust
+/// use tokio_debouncer::{DebounceMode, Debouncer, DebouncerGroup};
+/// use tokio::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let saves = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+/// let reloads = Debouncer::new(Duration::from_millis(500), DebounceMode::Trailing);
+/// let mut group = DebouncerGroup::new();
+/// group.push(saves.clone());
+/// group.push(reloads.clone());
+///
+/// saves.trigger();
+/// let (index, _guard) = group.ready().await;
+/// assert_eq!(index, 0);
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct DebouncerGroup {
+    members: Vec<Debouncer>,
+}
+
+impl DebouncerGroup {
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a debouncer to the group and return its index.
+    pub fn push(&mut self, debouncer: Debouncer) -> usize {
+        self.members.push(debouncer);
+        self.members.len() - 1
+    }
+
+    /// The member at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&Debouncer> {
+        self.members.get(index)
+    }
+
+    /// Number of debouncers in the group.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether the group has no members.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Wait until any member is ready to run.
+    /// Returns the member's index and a guard that finalizes its debounce state when dropped.
+    ///
+    /// When several members are ready at once, the lowest index wins.
+    /// An empty group never resolves.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`Debouncer::ready`].
+    pub async fn ready<'a>(&self) -> (usize, DebouncerGuard<'a>) {
+        let mut waits: Vec<_> = self
+            .members
+            .iter()
+            .map(|debouncer| Box::pin(debouncer.ready()))
+            .collect();
+        poll_fn(|cx| {
+            for (index, wait) in waits.iter_mut().enumerate() {
+                if let Poll::Ready(guard) = wait.as_mut().poll(cx) {
+                    return Poll::Ready((index, guard));
+                }
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
@@ -0,0 +1,47 @@
+//! Deterministic test helper (requires the `test-util` feature).
+
+use tokio::time::{self, Duration};
+
+use crate::{Debouncer, DebouncerStatus};
+
+/// Wraps a [`Debouncer`] for `start_paused` tests of debounced logic.
+///
+/// Replaces the usual `time::advance` plus `select!` race with a single call that answers
+/// "would `ready()` resolve now?". It moves Tokio's paused clock, so the debouncer must use
+/// the default [`TokioClock`](crate::TokioClock) inside a runtime with paused time.
+///
+/// ```rust
+/// use tokio::time::Duration;
+/// use tokio_debouncer::{DebounceMode, Debouncer, DebouncerTestHarness};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let harness = DebouncerTestHarness::new(Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing));
+/// harness.debouncer().trigger();
+/// assert!(!harness.advance_and_check(Duration::from_millis(50)).await);
+/// assert!(harness.advance_and_check(Duration::from_millis(50)).await);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DebouncerTestHarness {
+    debouncer: Debouncer,
+}
+
+impl DebouncerTestHarness {
+    /// Wrap a debouncer.
+    pub fn new(debouncer: Debouncer) -> Self {
+        Self { debouncer }
+    }
+
+    /// The wrapped debouncer, for triggering and claiming batches.
+    pub fn debouncer(&self) -> &Debouncer {
+        &self.debouncer
+    }
+
+    /// Advance the paused clock by `dur`, then report whether [`Debouncer::ready`] would
+    /// resolve immediately. Nothing is claimed.
+    pub async fn advance_and_check(&self, dur: Duration) -> bool {
+        time::advance(dur).await;
+        matches!(self.debouncer.status(), DebouncerStatus::Ready)
+    }
+}
@@ -0,0 +1,155 @@
+//! Per-key debouncing.
+
+use std::collections::HashMap;
+use std::future::{poll_fn, Future};
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Poll;
+use tokio::time::{Duration, Instant};
+
+use crate::notify::{notified, Notifier};
+use crate::{DebounceMode, Debouncer, DebouncerGuard, Mutex, MutexExt};
+
+/// Debouncer multiplexed over keys, e.g. one debounce window per user id.
+///
+/// Each key gets its own [`Debouncer`], created lazily on its first trigger.
+/// `ready()` resolves to whichever key becomes ready first.
+/// Can be cloned and shared between tasks.
+pub struct KeyedDebouncer<K> {
+    inner: Arc<KeyedInner<K>>,
+}
+
+impl<K> Clone for KeyedDebouncer<K> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+struct KeyedInner<K> {
+    cooldown: Duration,
+    mode: DebounceMode,
+    idle_timeout: Option<Duration>,
+    entries: Mutex<HashMap<K, KeyedEntry>>,
+    /// Signalled when a new key is added, so `ready()` can start watching it.
+    added: Notifier,
+}
+
+struct KeyedEntry {
+    debouncer: Debouncer,
+    last_active: Instant,
+}
+
+impl<K: Hash + Eq + Clone> KeyedDebouncer<K> {
+    /// Create a keyed debouncer whose per-key debouncers use `cooldown` and `mode`.
+    /// Entries are kept until the keyed debouncer is dropped.
+    pub fn new(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self::build(cooldown, mode, None)
+    }
+
+    /// Like [`new`](Self::new), but evicts a key once it has no pending trigger and
+    /// has not been triggered for `idle_timeout`. Eviction happens lazily on `trigger()` and `ready()`.
+    pub fn with_idle_timeout(cooldown: Duration, mode: DebounceMode, idle_timeout: Duration) -> Self {
+        Self::build(cooldown, mode, Some(idle_timeout))
+    }
+
+    fn build(cooldown: Duration, mode: DebounceMode, idle_timeout: Option<Duration>) -> Self {
+        Self {
+            inner: Arc::new(KeyedInner {
+                cooldown,
+                mode,
+                idle_timeout,
+                entries: Mutex::new(HashMap::new()),
+                added: Notifier::new(),
+            }),
+        }
+    }
+
+    /// Number of keys currently tracked.
+    pub fn len(&self) -> usize {
+        self.inner.entries.risky_lock().len()
+    }
+
+    /// Whether no keys are currently tracked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Trigger the debouncer for `key`, creating it on first use.
+    pub fn trigger(&self, key: K) {
+        let now = Instant::now();
+        let (debouncer, added) = {
+            let mut entries = self.inner.entries.risky_lock();
+            self.evict_idle(&mut entries, now);
+            let mut added = false;
+            let entry = entries.entry(key).or_insert_with(|| {
+                added = true;
+                KeyedEntry {
+                    debouncer: Debouncer::new(self.inner.cooldown, self.inner.mode),
+                    last_active: now,
+                }
+            });
+            entry.last_active = now;
+            (entry.debouncer.clone(), added)
+        };
+        debouncer.trigger();
+        if added {
+            self.inner.added.notify_waiters();
+        }
+    }
+
+    /// Wait until any key is ready to run.
+    /// Returns the key and a guard that finalizes that key's debounce state when dropped.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`Debouncer::ready`].
+    pub async fn ready<'a>(&self) -> (K, DebouncerGuard<'a>) {
+        loop {
+            notified!(self.inner.added => added);
+            let members: Vec<(K, Debouncer)> = {
+                let mut entries = self.inner.entries.risky_lock();
+                self.evict_idle(&mut entries, Instant::now());
+                entries
+                    .iter()
+                    .map(|(key, entry)| (key.clone(), entry.debouncer.clone()))
+                    .collect()
+            };
+            let mut waits: Vec<_> = members
+                .into_iter()
+                .map(|(key, debouncer)| {
+                    Box::pin(async move {
+                        let guard: DebouncerGuard<'a> = debouncer.ready().await;
+                        (key, guard)
+                    })
+                })
+                .collect();
+            // Resolve with the first ready key, or start over when a new key shows up
+            let ready = poll_fn(|cx| {
+                for wait in waits.iter_mut() {
+                    if let Poll::Ready(out) = wait.as_mut().poll(cx) {
+                        return Poll::Ready(Some(out));
+                    }
+                }
+                match Pin::new(&mut added).poll(cx) {
+                    Poll::Ready(()) => Poll::Ready(None),
+                    Poll::Pending => Poll::Pending,
+                }
+            })
+            .await;
+            if let Some(out) = ready {
+                return out;
+            }
+        }
+    }
+
+    fn evict_idle(&self, entries: &mut HashMap<K, KeyedEntry>, now: Instant) {
+        if let Some(idle_timeout) = self.inner.idle_timeout {
+            entries.retain(|_, entry| {
+                entry.debouncer.inner.state.risky_lock().triggered
+                    || now.saturating_duration_since(entry.last_active) < idle_timeout
+            });
+        }
+    }
+}
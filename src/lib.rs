@@ -7,7 +7,7 @@
 //! This crate provides a simple, robust, and deterministic debouncer for batching signals or jobs in async workflows.
 //! It is especially suited for job queues, event batching, and select-based async workers where you want to coalesce bursts of work and process them efficiently.
 //!
-//! - Supports both **leading** and **trailing** debounce modes.
+//! - Supports **leading**, **trailing**, and combined **both** debounce modes.
 //! - Designed for use with `tokio::select!` for robust, cancel-safe batching.
 //! - Can be triggered from any thread or task.
 //! - Fully tested with simulated time.
@@ -70,76 +70,608 @@
 //!
 //! If you need to do work after acquiring the guard, do it after awaiting `ready()` and let the guard drop naturally.
 
+// --- tracing feature support (no-ops when disabled) ---
+#[cfg(feature = "tracing")]
+macro_rules! trace {
+    ($($arg:tt)*) => { tracing::trace!($($arg)*) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {};
+}
+#[cfg(feature = "tracing")]
+macro_rules! debug {
+    ($($arg:tt)*) => { tracing::debug!($($arg)*) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {};
+}
+#[cfg(feature = "tracing")]
+macro_rules! warn {
+    ($($arg:tt)*) => { tracing::warn!($($arg)*) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! warn {
+    ($($arg:tt)*) => {};
+}
+
+mod builder;
+mod clock;
+mod config;
+mod group;
+#[cfg(feature = "test-util")]
+mod harness;
+mod keyed;
+mod notify;
+mod oneshot;
+#[cfg(feature = "runner")]
+mod runner;
+#[cfg(feature = "stream")]
+mod stream;
+mod value;
+
+pub use builder::{BuildError, DebouncerBuilder};
+#[cfg(feature = "async-std")]
+pub use clock::AsyncStdClock;
+pub use clock::{Clock, TokioClock, WallClock};
+pub use config::{DebouncerConfig, DebouncerSettings, DebouncerSnapshot};
+pub use group::DebouncerGroup;
+#[cfg(feature = "test-util")]
+pub use harness::DebouncerTestHarness;
+pub use keyed::KeyedDebouncer;
+pub use oneshot::OneShotDebouncer;
+#[cfg(feature = "runner")]
+pub use runner::DebouncerRunner;
+#[cfg(feature = "stream")]
+pub use stream::{debounce_stream, DebouncedReceiver, DebouncedStream, DebouncerStream};
+pub use value::{OverflowPolicy, QueueDebouncer, QueueDebouncerBuilder, SetDebouncer, ValueDebouncer, ValueGuard};
+
+use std::any::Any;
+use std::collections::hash_map::RandomState;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
 use std::marker::PhantomData;
-use std::sync::{Arc};
-use tokio::sync::Notify;
+use std::pin::Pin;
+#[cfg(debouncer_loom)]
+use loom::sync::atomic::{AtomicU64, AtomicUsize};
+#[cfg(not(debouncer_loom))]
+use std::sync::atomic::{AtomicU64, AtomicUsize};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+use tokio::sync::{mpsc, watch, Semaphore};
+use tokio::task::JoinHandle;
 use tokio::time::{Duration, Instant};
 
+use notify::{notified, Notifier};
+
 
-// --- parking_lot feature support ---
-#[cfg(feature = "parking_lot")]
+// --- parking_lot feature support (loom's model checker replaces both under `cfg(debouncer_loom)`) ---
+#[cfg(debouncer_loom)]
+pub use loom::sync::{Mutex, MutexGuard};
+#[cfg(all(not(debouncer_loom), feature = "parking_lot"))]
 pub use parking_lot::{Mutex, MutexGuard};
-#[cfg(not(feature = "parking_lot"))]
+#[cfg(all(not(debouncer_loom), not(feature = "parking_lot")))]
 pub use std::sync::{Mutex, MutexGuard};
 
 
 /// --- MutexExt for poison handling ---
-#[cfg(not(feature = "parking_lot"))]
+#[cfg(any(debouncer_loom, not(feature = "parking_lot")))]
 pub trait MutexExt<T> {
     /// Lock the mutex, panicking if poisoned.
-    fn risky_lock(&self) -> MutexGuard<T>;
+    fn risky_lock(&self) -> MutexGuard<'_, T>;
+    /// Lock the mutex if it is free; `None` if it is held or poisoned.
+    fn risky_try_lock(&self) -> Option<MutexGuard<'_, T>>;
 }
-#[cfg(not(feature = "parking_lot"))]
+#[cfg(any(debouncer_loom, not(feature = "parking_lot")))]
 impl<T> MutexExt<T> for Mutex<T> {
-    fn risky_lock(&self) -> MutexGuard<T> {
+    fn risky_lock(&self) -> MutexGuard<'_, T> {
         self.lock().expect("Mutex poisoned")
     }
+    fn risky_try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.try_lock().ok()
+    }
 }
-#[cfg(feature = "parking_lot")]
+#[cfg(all(not(debouncer_loom), feature = "parking_lot"))]
 pub trait MutexExt<T> {
     /// Lock the parking_lot mutex (never poisoned).
-    fn risky_lock(&self) -> MutexGuard<T>;
+    fn risky_lock(&self) -> MutexGuard<'_, T>;
+    /// Lock the mutex if it is free; `None` if it is held.
+    fn risky_try_lock(&self) -> Option<MutexGuard<'_, T>>;
 }
-#[cfg(feature = "parking_lot")]
+#[cfg(all(not(debouncer_loom), feature = "parking_lot"))]
 impl<T> MutexExt<T> for Mutex<T> {
-    fn risky_lock(&self) -> MutexGuard<T> {
+    fn risky_lock(&self) -> MutexGuard<'_, T> {
         self.lock()
     }
+    fn risky_try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.try_lock()
+    }
 }
 
-/// The debounce mode: Leading or Trailing.
-/// - Leading: fires immediately, then cools down.
+/// The debounce mode: Leading, Trailing, Both, Throttle or RateLimited.
+/// - Leading: fires immediately, then cools down. Triggers during the cooldown fire once it
+///   ends, so continuous triggers fire at `0, cooldown, 2 * cooldown, ...` after the first,
+///   measured from the scheduled fire times rather than the end of processing.
 /// - Trailing: fires after the last trigger and cooldown (default).
-#[derive(Debug)]
+/// - Both: fires immediately on the first trigger of an idle period, then once more
+///   after the cooldown of silence if further triggers arrived in the meantime.
+/// - Throttle: fires immediately, then at most once per cooldown on a fixed cadence.
+///   New triggers never extend the deadline, and the cadence is measured between
+///   scheduled fires rather than from the end of processing.
+/// - RateLimited: fires immediately while fewer than `max` batches were committed in the
+///   trailing `window`, otherwise as soon as the oldest of them ages out. The cooldown is not
+///   used. A `max` of 0 is treated as 1. With `serde` it reads as
+///   `{"rate_limited": {"max": 5, "window_ms": 1000}}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum DebounceMode {
     Leading,
+    #[default]
     Trailing,
+    Both,
+    Throttle,
+    #[cfg_attr(feature = "serde", serde(rename = "rate_limited"))]
+    RateLimited {
+        max: usize,
+        #[cfg_attr(feature = "serde", serde(rename = "window_ms", with = "config::duration_ms"))]
+        window: Duration,
+    },
+}
+
+/// Priority of a trigger, see [`Debouncer::trigger_with_priority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Cooldown growth for consecutive fires, see [`DebouncerBuilder::backoff`].
+#[derive(Debug, Clone, Copy)]
+struct Backoff {
+    factor: u32,
+    max_cooldown: Duration,
 }
 
 /// Internal state for the debouncer.
 struct DebouncerState {
+    /// Debounce mode; adjustable at runtime.
+    mode: DebounceMode,
+    /// Minimum time between fires; adjustable at runtime.
+    cooldown: Duration,
+    /// Cooldown actually applied; grows past `cooldown` under backoff.
+    effective_cooldown: Duration,
     has_run: bool,
     last_run: Instant,
+    /// When the last batch fired, if any.
+    last_fired: Option<Instant>,
+    /// When the last batch finished processing; tracked only with `gap_after_processing`.
+    finished: Option<Instant>,
     triggered: bool,
+    /// When the oldest un-serviced trigger arrived, for the max-wait cap.
+    first_trigger: Option<Instant>,
+    /// When the first trigger arrived while the pending batch's guard was held; it becomes
+    /// `first_trigger` of the batch left pending when the guard is finalized.
+    late_first_trigger: Option<Instant>,
+    /// Number of triggers coalesced into the pending batch.
+    coalesced: u64,
+    /// Fire the pending batch immediately, bypassing the cooldown.
+    force: bool,
+    /// No more triggers are accepted; waiters resolve to `None` once nothing is pending.
+    closed: bool,
+    /// Firing is suspended; triggers still accumulate.
+    paused: bool,
+    /// A guard for the pending batch has been handed out and not yet finalized.
+    claimed: bool,
+    /// Random delay added to the pending batch's deadline.
+    jitter: Duration,
+    /// Jitter generator state.
+    rng: u64,
+    /// The pending batch fires on the leading edge (Both mode only).
+    leading: bool,
+    /// Further triggers arrived after the leading one, so a trailing fire is owed (Both mode only).
+    trailing: bool,
+    /// Running aggregate of the time between consecutive fires.
+    intervals: IntervalStats,
+    /// Commit times of the most recent fires, oldest first (RateLimited mode only).
+    recent_fires: VecDeque<Instant>,
+    /// Distinct keys passed to `trigger_keyed` for the pending batch.
+    keys: Vec<BatchKey>,
+    /// Keys of the batch whose guard is currently held.
+    claimed_keys: Vec<BatchKey>,
+    /// Earliest deadline requested by a prioritized trigger in the pending batch.
+    priority_deadline: Option<Instant>,
+    /// Fire time requested by `DebouncerGuard::reschedule`, replacing the cooldown deadline.
+    scheduled: Option<Instant>,
+    /// Callback run each time a batch is committed.
+    on_fire: Option<Arc<dyn Fn() + Send + Sync>>,
 }
 
 /// Shared inner struct for Debouncer.
 struct DebouncerInner {
-    mode: DebounceMode,
-    notifier: Notify,
-    cooldown: Duration,
+    clock: Arc<dyn Clock>,
+    notifier: Notifier,
+    /// Signalled once by `close()`, for tasks awaiting `closed()`.
+    on_close: Notifier,
+    /// Single permit that orders `ready_fair()` callers.
+    fair: Semaphore,
+    max_wait: Option<Duration>,
+    /// Fire as soon as this many triggers are coalesced, regardless of the cooldown.
+    max_batch: Option<usize>,
+    backoff: Option<Backoff>,
+    /// Leading mode discards triggers during the cooldown instead of firing them afterwards.
+    strict_leading: bool,
+    /// Leading mode discards triggers this soon after a leading fire.
+    dedup_window: Duration,
+    /// Leading mode restarts the cooldown on every trigger that arrives during it.
+    cooldown_from_trigger: bool,
+    /// The next batch may not fire within the cooldown after the last one finished.
+    gap_after_processing: bool,
+    /// Run `on_fire` for a batch still pending when the last handle is dropped.
+    fire_on_last_drop: bool,
+    /// Claiming a batch also closes the debouncer, so no trigger can join it late.
+    close_on_claim: bool,
+    /// Cooldown for `trigger_with_priority`, indexed by `Priority`.
+    priority_cooldowns: [Option<Duration>; 3],
+    jitter: Duration,
+    /// Lifetime count of `trigger()` calls; wraps on overflow.
+    triggers: AtomicU64,
+    /// Lifetime count of committed batches; wraps on overflow.
+    fires: AtomicU64,
+    /// Counter values at the last `reset_stats()`, subtracted by `stats()`.
+    stats_base: [AtomicU64; 2],
+    /// Tasks currently inside the wait loop.
+    waiters: AtomicUsize,
     state: Mutex<DebouncerState>,
 }
 
+/// `instant + dur`, saturating to a far-future instant instead of overflowing, so enormous
+/// cooldowns such as `Duration::MAX` mean "never on time, only via `flush()`".
+fn add_saturating(instant: Instant, dur: Duration) -> Instant {
+    instant.checked_add(dur).unwrap_or_else(|| {
+        // Roughly 30 years, as Tokio's own far-future sleeps use
+        instant
+            .checked_add(Duration::from_secs(86400 * 365 * 30))
+            .unwrap_or(instant)
+    })
+}
+
+/// Log when a non-zero cooldown is finer than the timer can honour.
+fn warn_below_resolution(cooldown: Duration) {
+    if !cooldown.is_zero() && cooldown < Debouncer::TIMER_RESOLUTION {
+        warn!(
+            ?cooldown,
+            resolution = ?Debouncer::TIMER_RESOLUTION,
+            "debouncer cooldown is below the timer resolution and will be rounded up"
+        );
+    }
+}
+
+/// A key passed to `trigger_keyed`, stored type-erased so `Debouncer` stays non-generic.
+struct BatchKey {
+    value: Box<dyn Any + Send>,
+    /// Compares two erased keys of the type this one was created from.
+    same: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl BatchKey {
+    fn new<K: PartialEq + Send + 'static>(key: K) -> Self {
+        fn same<K: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
+            matches!((a.downcast_ref::<K>(), b.downcast_ref::<K>()), (Some(a), Some(b)) if a == b)
+        }
+        Self {
+            value: Box::new(key),
+            same: same::<K>,
+        }
+    }
+
+    /// Add this key to `keys` unless an equal one is already there.
+    fn insert_into(self, keys: &mut Vec<BatchKey>) {
+        let value: &dyn Any = &*self.value;
+        if !keys.iter().any(|key| (self.same)(value, &*key.value)) {
+            keys.push(self);
+        }
+    }
+}
+
+/// Counts a task as parked in the wait loop until dropped, including on cancellation.
+struct WaiterCount<'a>(&'a AtomicUsize);
+
+impl<'a> WaiterCount<'a> {
+    fn enter(waiters: &'a AtomicUsize) -> Self {
+        waiters.fetch_add(1, Ordering::Relaxed);
+        Self(waiters)
+    }
+}
+
+impl Drop for WaiterCount<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// SplitMix64 step; plenty for spreading deadlines.
+fn next_random(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Readiness of the pending batch at a given instant.
+enum Readiness {
+    /// Nothing has been triggered.
+    Idle,
+    /// Nothing is pending and the debouncer has been closed.
+    Closed,
+    /// Triggered, but the batch may not fire before the deadline.
+    Waiting(Instant),
+    /// Triggered and eligible to fire now; eligible since the given instant.
+    Ready(Instant),
+}
+
+/// Description of a batch, captured when its guard is handed out, see
+/// [`Debouncer::ready_with_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchInfo {
+    /// Number of triggers coalesced into the batch.
+    pub coalesced: u64,
+    /// When the first trigger of the batch arrived.
+    pub first_trigger: Instant,
+    /// When the batch became eligible to fire.
+    pub ready_at: Instant,
+}
+
 impl DebouncerInner {
-    /// Finalize the debounce state after work is done or dropped.
-    fn finalize(&self, pending: bool) {
+    /// Draw the jitter offset for a new batch.
+    fn draw_jitter(&self, state: &mut DebouncerState) -> Duration {
+        let range = self.jitter.as_nanos().min(u64::MAX as u128) as u64;
+        if range == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_nanos(next_random(&mut state.rng) % range)
+    }
+
+    /// Claim a ready batch for the caller about to create its guard.
+    ///
+    /// Every path that hands out a guard must claim under the same lock acquisition that saw
+    /// `Readiness::Ready`, so each batch is handed out exactly once.
+    fn claim(&self, state: &mut DebouncerState, ready_at: Instant) -> BatchInfo {
+        state.claimed = true;
+        state.claimed_keys = std::mem::take(&mut state.keys);
+        if self.close_on_claim {
+            state.closed = true;
+        }
+        BatchInfo {
+            coalesced: state.coalesced,
+            first_trigger: state.first_trigger.unwrap_or(ready_at),
+            ready_at,
+        }
+    }
+
+    /// Whether the pending batch has reached the `max_batch` threshold.
+    fn batch_full(&self, state: &DebouncerState) -> bool {
+        matches!(self.max_batch, Some(max_batch) if state.coalesced >= max_batch as u64)
+    }
+
+    /// Push `deadline` back to honour `gap_after_processing`, if enabled.
+    fn gap_floor(&self, state: &DebouncerState, deadline: Instant) -> Instant {
+        match state.finished {
+            Some(finished) => deadline.max(add_saturating(finished, state.effective_cooldown)),
+            None => deadline,
+        }
+    }
+
+    /// Evaluate whether the pending batch may fire at `now`.
+    fn readiness(&self, state: &DebouncerState, now: Instant) -> Readiness {
+        self.readiness_as(state, now, state.mode)
+    }
+
+    /// Like `readiness`, but applying `mode`'s rule instead of the configured one.
+    fn readiness_as(&self, state: &DebouncerState, now: Instant, mode: DebounceMode) -> Readiness {
+        if state.claimed {
+            // Another caller holds the guard for this batch; wait for it to be finalized
+            return Readiness::Idle;
+        }
+        if !state.triggered {
+            return if state.closed {
+                Readiness::Closed
+            } else {
+                Readiness::Idle
+            };
+        }
+        if state.paused {
+            // Waiters park until `resume` notifies them
+            return Readiness::Idle;
+        }
+        if state.force || self.batch_full(state) {
+            return Readiness::Ready(now);
+        }
+        if let DebounceMode::RateLimited { max, window } = mode {
+            // Eligible once the fire `max` places back has left the window
+            let first = state.first_trigger.unwrap_or(now).min(now);
+            let fires = &state.recent_fires;
+            let mut eligible = match fires.len().checked_sub(max.max(1)) {
+                Some(index) => add_saturating(fires[index], window).max(first),
+                None => first,
+            };
+            if let Some(at) = state.scheduled {
+                eligible = eligible.max(at);
+            }
+            eligible = self.gap_floor(state, eligible);
+            return if now >= eligible {
+                Readiness::Ready(eligible)
+            } else {
+                Readiness::Waiting(eligible)
+            };
+        }
+        let mut next_allowed = add_saturating(state.last_run, state.effective_cooldown);
+        if let (Some(first), Some(max_wait)) = (state.first_trigger, self.max_wait) {
+            next_allowed = next_allowed.min(add_saturating(first, max_wait));
+        }
+        next_allowed = add_saturating(next_allowed, state.jitter);
+        if let Some(at) = state.scheduled {
+            next_allowed = at;
+        }
+        if let Some(deadline) = state.priority_deadline {
+            next_allowed = next_allowed.min(deadline);
+        }
+        next_allowed = self.gap_floor(state, next_allowed);
+        let edge = match mode {
+            DebounceMode::Leading | DebounceMode::Throttle => !state.has_run,
+            DebounceMode::Trailing | DebounceMode::RateLimited { .. } => false,
+            DebounceMode::Both => state.leading,
+        };
+        if edge {
+            // Leading-edge fires are eligible as soon as they are triggered
+            Readiness::Ready(state.first_trigger.unwrap_or(now).min(now))
+        } else if now >= next_allowed {
+            Readiness::Ready(next_allowed)
+        } else {
+            Readiness::Waiting(next_allowed)
+        }
+    }
+
+    /// Finalize the debounce state after work is done or dropped, committing the claimed
+    /// `batch`.
+    ///
+    /// Triggers that arrived while the guard was held are not part of the batch; they stay
+    /// pending and fire after the cooldown. With a `follow_up` delay, a batch stays pending
+    /// and fires that long after the commit.
+    fn finalize(&self, batch: &BatchInfo, follow_up: Option<Duration>) {
         let mut state = self.state.risky_lock();
+        state.claimed = false;
+        state.claimed_keys.clear();
         if state.triggered {
+            let mode = state.mode;
+            debug!(?mode, coalesced = state.coalesced, "debouncer batch committed");
+            self.fires.fetch_add(1, Ordering::Relaxed);
+            if let Some(on_fire) = &state.on_fire {
+                on_fire();
+            }
+            // In Both mode a leading fire that saw more triggers keeps the batch open for the trailing fire
+            let trailing = state.leading && state.trailing;
+            let now = self.clock.now();
+            let fired_at = match (mode, state.first_trigger) {
+                // With the gap enforced, every mode measures the next window from the commit
+                _ if self.gap_after_processing => now,
+                // Leading and Throttle advance to the slot the batch became eligible in, so
+                // processing time never drifts the cadence. A batch fired ahead of its slot
+                // (flush, max_batch, max_wait, priority...) counts from when it actually fired.
+                (DebounceMode::Leading | DebounceMode::Throttle, Some(first)) if state.has_run => {
+                    let slot = first.max(add_saturating(state.last_run, state.effective_cooldown));
+                    slot.min(batch.ready_at.max(first)).min(now)
+                }
+                (DebounceMode::Leading | DebounceMode::Throttle, Some(first)) => first.min(now),
+                _ => now,
+            };
+            if let Some(backoff) = self.backoff {
+                // A batch opened within the cooldown of the previous fire extends the backoff
+                let consecutive = matches!(
+                    (state.last_fired, state.first_trigger),
+                    (Some(last), Some(first)) if first < add_saturating(last, state.effective_cooldown)
+                );
+                if consecutive {
+                    let grown = state.effective_cooldown.saturating_mul(backoff.factor);
+                    state.effective_cooldown = grown.min(backoff.max_cooldown).max(state.cooldown);
+                }
+            }
             state.has_run = true;
-            state.triggered = pending;
-            state.last_run = tokio::time::Instant::now();
-            self.notifier.notify_one();
+            let late = state.coalesced.saturating_sub(batch.coalesced);
+            state.triggered = trailing || late > 0;
+            let late_first_trigger = state.late_first_trigger.take();
+            state.first_trigger = if state.triggered {
+                Some(late_first_trigger.unwrap_or(now))
+            } else {
+                None
+            };
+            state.jitter = if state.triggered {
+                self.draw_jitter(&mut state)
+            } else {
+                Duration::ZERO
+            };
+            state.coalesced = late;
+            state.force = false;
+            state.leading = false;
+            state.trailing = false;
+            state.priority_deadline = None;
+            state.scheduled = None;
+            state.last_run = fired_at;
+            if let Some(previous) = state.last_fired {
+                state.intervals.record(fired_at.saturating_duration_since(previous));
+            }
+            state.last_fired = Some(fired_at);
+            if self.gap_after_processing {
+                state.finished = Some(now);
+            }
+            if let DebounceMode::RateLimited { max, .. } = mode {
+                if state.recent_fires.len() >= max.max(1) {
+                    state.recent_fires.pop_front();
+                }
+                state.recent_fires.push_back(fired_at);
+            }
+            if let Some(delay) = follow_up {
+                if !state.triggered {
+                    state.triggered = true;
+                    state.first_trigger = Some(now);
+                }
+                state.scheduled = Some(add_saturating(now, delay));
+            }
+        }
+        // Wake every waiter: the batch is released either way
+        self.notifier.notify_waiters();
+    }
+
+    /// Copies of the claimed batch's keys of type `K`.
+    fn claimed_keys<K: Clone + 'static>(&self) -> Vec<K> {
+        let state = self.state.risky_lock();
+        state.claimed_keys.iter().filter_map(|key| key.value.downcast_ref::<K>().cloned()).collect()
+    }
+
+    /// Count every trigger recorded so far into the claimed batch, whose payload has just been
+    /// taken with theirs, so none of them is owed a fire of its own; returns the new count.
+    fn absorb_late(&self) -> u64 {
+        let mut state = self.state.risky_lock();
+        state.trailing = false;
+        state.late_first_trigger = None;
+        state.coalesced
+    }
+
+    /// Hand a claimed batch back without committing it, leaving it pending.
+    fn release(&self) {
+        {
+            let mut state = self.state.risky_lock();
+            state.claimed = false;
+            state.late_first_trigger = None;
+            // The batch is pending again, together with keys that arrived meanwhile
+            let mut keys = std::mem::take(&mut state.claimed_keys);
+            for key in std::mem::take(&mut state.keys) {
+                key.insert_into(&mut keys);
+            }
+            state.keys = keys;
+        }
+        self.notifier.notify_waiters();
+    }
+}
+
+impl Drop for DebouncerInner {
+    fn drop(&mut self) {
+        if !self.fire_on_last_drop {
+            return;
+        }
+        let state = self.state.risky_lock();
+        if state.triggered {
+            debug!(coalesced = state.coalesced, "debouncer dropped with a pending batch");
+            if let Some(on_fire) = &state.on_fire {
+                on_fire();
+            }
         }
     }
 }
@@ -148,22 +680,114 @@ impl DebouncerInner {
 ///
 /// The debounce state is finalized automatically when this guard is dropped.
 /// You do not need to call any method to commit the debounce; simply let the guard go out of scope.
+///
+/// A guard from [`ready`](Debouncer::ready) holds its own reference to the shared state, and
+/// the lifetime `'a` is chosen by the caller. This lets guards outlive the handle they came
+/// from, which streams, keyed debouncers and groups rely on when they hand out guards of
+/// debouncers they own internally. A guard from [`ready_ref`](Debouncer::ready_ref) borrows
+/// the handle instead, skipping the reference-count increment and decrement per fire.
 pub struct DebouncerGuard<'a> {
-    inner: Arc<DebouncerInner>,
+    inner: GuardInner<'a>,
     completed: bool,
+    batch: BatchInfo,
     _not_send: PhantomData<*const ()>,
     _not_static: PhantomData<&'a ()>,
 }
 
+/// How a [`DebouncerGuard`] reaches the shared state.
+enum GuardInner<'a> {
+    Shared(Arc<DebouncerInner>),
+    Borrowed(&'a DebouncerInner),
+}
+
+impl std::ops::Deref for GuardInner<'_> {
+    type Target = DebouncerInner;
+
+    fn deref(&self) -> &DebouncerInner {
+        match self {
+            GuardInner::Shared(inner) => inner,
+            GuardInner::Borrowed(inner) => inner,
+        }
+    }
+}
+
 impl<'a> DebouncerGuard<'a> {
-    fn new(inner: Arc<DebouncerInner>) -> Self {
+    fn new(inner: Arc<DebouncerInner>, batch: BatchInfo) -> Self {
+        Self::with_inner(GuardInner::Shared(inner), batch)
+    }
+
+    fn with_inner(inner: GuardInner<'a>, batch: BatchInfo) -> Self {
         Self {
             inner,
             completed: false,
+            batch,
             _not_send: PhantomData,
             _not_static: PhantomData,
         }
     }
+
+    /// Number of `trigger()` calls coalesced into this batch.
+    ///
+    /// The count includes the trigger that initially set the flag, so it is at least 1
+    /// for a batch started by a trigger.
+    pub fn coalesced_count(&self) -> u64 {
+        self.batch.coalesced
+    }
+
+    /// The distinct keys of type `K` passed to [`trigger_keyed`](Debouncer::trigger_keyed)
+    /// for this batch, in arrival order. Keys of other types are skipped.
+    pub fn keys<K: Clone + 'static>(&self) -> Vec<K> {
+        self.inner.claimed_keys()
+    }
+
+    /// When the batch became eligible to fire, e.g. the end of its cooldown.
+    ///
+    /// Compare with the current time to measure how long the batch waited to be processed.
+    pub fn ready_at(&self) -> Instant {
+        self.batch.ready_at
+    }
+
+    /// Commit the batch now instead of when the guard goes out of scope.
+    ///
+    /// The cooldown starts immediately and new triggers open the next batch, so any work
+    /// done after `commit()` is outside this batch's debounce window.
+    pub fn commit(mut self) {
+        self.completed = true;
+        self.inner.finalize(&self.batch, None);
+    }
+
+    /// Commit the batch and schedule a follow-up batch to fire `delay` from now without a
+    /// fresh trigger, e.g. when processing found more work to do. The delay replaces the
+    /// cooldown for that batch; triggers arriving in the meantime coalesce into it.
+    pub fn reschedule(mut self, delay: Duration) {
+        self.completed = true;
+        self.inner.finalize(&self.batch, Some(delay));
+    }
+
+    /// Give the batch back without committing it: `last_run` does not advance and the
+    /// trigger stays pending, so the next `ready()` fires it again. Use this when the work
+    /// turned out not to count as a fire.
+    pub fn abort(mut self) {
+        self.completed = true;
+        self.inner.release();
+    }
+}
+
+impl<'a> fmt::Debug for DebouncerGuard<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebouncerGuard")
+            .field("completed", &self.completed)
+            .field("coalesced", &self.batch.coalesced)
+            .field("ready_at", &self.batch.ready_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> DebouncerGuard<'a> {
+    /// Fold the triggers that arrived while this guard was held into its batch.
+    fn absorb_late(&mut self) {
+        self.batch.coalesced = self.inner.absorb_late();
+    }
 }
 
 impl<'a> Drop for DebouncerGuard<'a> {
@@ -173,62 +797,888 @@ impl<'a> Drop for DebouncerGuard<'a> {
     /// the debounce state is still committed and the next batch can proceed.
     fn drop(&mut self) {
         if !self.completed {
-            let inner = self.inner.clone();
             self.completed = true;
-            inner.finalize(false);
+            self.inner.finalize(&self.batch, None);
         }
     }
 }
 
+/// Owned guard returned by Debouncer::ready_owned().
+///
+/// Unlike [`DebouncerGuard`] it is `Send + 'static`, so it can be moved into a spawned task
+/// or stored in a struct. The debounce state is finalized when it is dropped.
+pub struct OwnedDebouncerGuard {
+    inner: Arc<DebouncerInner>,
+    completed: bool,
+    batch: BatchInfo,
+}
+
+impl OwnedDebouncerGuard {
+    /// Fold the triggers that arrived while this guard was held into its batch.
+    fn absorb_late(&mut self) {
+        self.batch.coalesced = self.inner.absorb_late();
+    }
+
+    /// Number of `trigger()` calls coalesced into this batch, including the first.
+    pub fn coalesced_count(&self) -> u64 {
+        self.batch.coalesced
+    }
+
+    /// The keys of this batch, see [`DebouncerGuard::keys`].
+    pub fn keys<K: Clone + 'static>(&self) -> Vec<K> {
+        self.inner.claimed_keys()
+    }
+
+    /// When the batch became eligible to fire, see [`DebouncerGuard::ready_at`].
+    pub fn ready_at(&self) -> Instant {
+        self.batch.ready_at
+    }
+
+    /// Commit the batch now, see [`DebouncerGuard::commit`].
+    pub fn commit(mut self) {
+        self.completed = true;
+        self.inner.finalize(&self.batch, None);
+    }
+
+    /// Commit the batch and schedule a follow-up, see [`DebouncerGuard::reschedule`].
+    pub fn reschedule(mut self, delay: Duration) {
+        self.completed = true;
+        self.inner.finalize(&self.batch, Some(delay));
+    }
+
+    /// Give the batch back without committing it, see [`DebouncerGuard::abort`].
+    pub fn abort(mut self) {
+        self.completed = true;
+        self.inner.release();
+    }
+}
+
+impl fmt::Debug for OwnedDebouncerGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedDebouncerGuard")
+            .field("completed", &self.completed)
+            .field("coalesced", &self.batch.coalesced)
+            .field("ready_at", &self.batch.ready_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for OwnedDebouncerGuard {
+    /// Finalizes the debounce state when the guard is dropped.
+    fn drop(&mut self) {
+        if !self.completed {
+            self.completed = true;
+            self.inner.finalize(&self.batch, None);
+        }
+    }
+}
+
+/// Error returned by [`Debouncer::try_trigger`] once the debouncer is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClosedError;
+
+impl fmt::Display for ClosedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("debouncer is closed")
+    }
+}
+
+impl std::error::Error for ClosedError {}
+
+/// Counters returned by [`Debouncer::stats`].
+///
+/// `triggers / fires` gives the coalescing ratio. The counters are `u64` and wrap around
+/// instead of panicking, even in debug builds; at a million triggers per second that takes
+/// over half a million years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DebouncerStats {
+    /// Total number of accepted `trigger()` calls. Triggers ignored because the debouncer is
+    /// closed, or discarded by strict leading or the dedup window, are not counted.
+    pub triggers: u64,
+    /// Total number of batches fired (guards finalized).
+    pub fires: u64,
+}
+
+/// Aggregate of the time between consecutive fires, returned by [`Debouncer::interval_stats`].
+///
+/// All durations are zero until at least two batches have fired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IntervalStats {
+    /// Shortest interval between two consecutive fires.
+    pub min: Duration,
+    /// Longest interval between two consecutive fires.
+    pub max: Duration,
+    /// Mean interval between consecutive fires.
+    pub mean: Duration,
+    /// Number of intervals recorded, i.e. fires minus one.
+    pub count: u64,
+}
+
+impl IntervalStats {
+    fn record(&mut self, interval: Duration) {
+        if self.count == 0 {
+            self.min = interval;
+            self.max = interval;
+        } else {
+            self.min = self.min.min(interval);
+            self.max = self.max.max(interval);
+        }
+        // Incremental mean in nanoseconds, so no running total can overflow
+        let mean = self.mean.as_nanos() as i128;
+        let delta = (interval.as_nanos() as i128 - mean) / (self.count as i128 + 1);
+        self.mean = Duration::from_nanos((mean + delta).max(0) as u64);
+        self.count = self.count.saturating_add(1);
+    }
+}
+
+/// Mutable copy of the core debounce state, handed to the closure of [`Debouncer::with_state`].
+///
+/// Changes are written back when the closure returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebouncerStateView {
+    /// Whether a batch has fired before; clear it to re-arm the leading edge.
+    pub has_run: bool,
+    /// Whether a batch is pending.
+    pub triggered: bool,
+    /// Start of the current cooldown window: the last fire, or in Trailing and Both modes
+    /// the last trigger.
+    pub last_run: Instant,
+    /// Number of triggers coalesced into the pending batch.
+    pub coalesced: u64,
+}
+
+/// Snapshot of a debouncer's state returned by [`Debouncer::status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebouncerStatus {
+    /// Nothing is pending, the pending batch's guard is already held, or the debouncer
+    /// is paused.
+    Idle,
+    /// A batch is pending and may fire in `fires_in`.
+    Pending { fires_in: Duration },
+    /// A batch is pending and eligible to fire now.
+    Ready,
+    /// The debouncer is closed and nothing is pending.
+    Closed,
+}
+
+/// In-flight wait driven by [`Debouncer::poll_ready`].
+type PollFuture = Pin<Box<dyn Future<Output = Option<BatchInfo>> + Send>>;
+
 /// Debouncer struct for batching events or jobs.
 /// Can be cloned and shared between tasks.
-#[derive(Clone)]
+///
+/// To learn which keys were touched during a batch, trigger with
+/// [`trigger_keyed`](Self::trigger_keyed); to time each key separately, use a
+/// [`KeyedDebouncer`].
 pub struct Debouncer {
     inner: Arc<DebouncerInner>,
+    /// Wait state for `poll_ready()`; each handle has its own.
+    polling: Mutex<Option<PollFuture>>,
+}
+
+impl fmt::Debug for Debouncer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Debouncer");
+        // Never block: this may run while the state lock is held, e.g. from an on_fire callback
+        match self.inner.state.risky_try_lock() {
+            Some(state) => debug
+                .field("mode", &state.mode)
+                .field("cooldown", &state.cooldown)
+                .field("triggered", &state.triggered)
+                .field("has_run", &state.has_run),
+            None => debug.field("state", &format_args!("<locked>")),
+        };
+        debug.finish_non_exhaustive()
+    }
+}
+
+impl Clone for Debouncer {
+    fn clone(&self) -> Self {
+        Self::from_inner(self.inner.clone())
+    }
+}
+
+impl Default for Debouncer {
+    /// A trailing debouncer with the [`DEFAULT_COOLDOWN`](Debouncer::DEFAULT_COOLDOWN).
+    fn default() -> Self {
+        Self::from_builder(DebouncerBuilder::new())
+    }
 }
 
 impl Debouncer {
+    /// Cooldown used by `Debouncer::default()` and `DebouncerBuilder::new()`.
+    pub const DEFAULT_COOLDOWN: Duration = Duration::from_millis(100);
+
+    /// Granularity of Tokio's timer. With [`TokioClock`], deadlines are rounded up to the
+    /// next tick, so a non-zero cooldown below this behaves like one of exactly this length
+    /// and sub-millisecond bursts coalesce into one batch. Such cooldowns are accepted, but
+    /// a warning is logged when the `tracing` feature is enabled.
+    pub const TIMER_RESOLUTION: Duration = Duration::from_millis(1);
+
     /// Create a new Debouncer with a cooldown time and mode (Leading or Trailing).
     /// Cooldown is the minimum time between triggers.
+    ///
+    /// A cooldown of `Duration::ZERO` is well-defined in every mode: each trigger fires as
+    /// soon as a waiter runs, which is handy for testing downstream logic. Triggers arriving
+    /// while a guard is held still coalesce into that batch.
     pub fn new(cooldown: Duration, mode: DebounceMode) -> Self {
-        let inner = Arc::new(DebouncerInner {
-            notifier: Notify::new(),
+        Self::from_builder(DebouncerBuilder::new().cooldown(cooldown).mode(mode))
+    }
+
+    /// Create a new Debouncer with a cooldown given in milliseconds.
+    pub fn from_millis(cooldown_ms: u64, mode: DebounceMode) -> Self {
+        Self::new(Duration::from_millis(cooldown_ms), mode)
+    }
+
+    /// Create a new Debouncer with a cooldown given in seconds.
+    pub fn from_secs(cooldown_secs: u64, mode: DebounceMode) -> Self {
+        Self::new(Duration::from_secs(cooldown_secs), mode)
+    }
+
+    /// Create a new Debouncer that fires at most `max_wait` after the first un-serviced trigger,
+    /// even if triggers keep arriving and pushing the cooldown forward.
+    ///
+    /// A `max_wait` shorter than `cooldown` is raised to `cooldown`; use
+    /// [`DebouncerBuilder::max_wait`] to have it rejected with
+    /// [`BuildError::MaxWaitBelowCooldown`] instead.
+    pub fn with_max_wait(cooldown: Duration, mode: DebounceMode, max_wait: Duration) -> Self {
+        Self::from_builder(
+            DebouncerBuilder::new()
+                .cooldown(cooldown)
+                .mode(mode)
+                .max_wait(max_wait.max(cooldown)),
+        )
+    }
+
+    /// Create a [`DebounceMode::Both`] debouncer: it fires once immediately on the first
+    /// trigger of a burst and once more `cooldown` after the burst ends, e.g. instant
+    /// feedback plus a final commit for search-as-you-type.
+    pub fn lead_tail(cooldown: Duration) -> Self {
+        Self::from_builder(DebouncerBuilder::new().cooldown(cooldown).mode(DebounceMode::Both))
+    }
+
+    /// Create a new Debouncer that reads time from a custom [`Clock`].
+    pub fn with_clock(cooldown: Duration, mode: DebounceMode, clock: impl Clock) -> Self {
+        Self::from_builder(DebouncerBuilder::new().cooldown(cooldown).mode(mode).clock(clock))
+    }
+
+    /// Start building a Debouncer with more options than `new()` offers.
+    pub fn builder() -> DebouncerBuilder {
+        DebouncerBuilder::new()
+    }
+
+    /// Create a Debouncer from a builder, without validating it.
+    ///
+    /// Callers must pass a configuration `DebouncerBuilder::build` would accept: `build`
+    /// validates before calling this, and the other constructors only set options that
+    /// cannot be invalid, or clamp them.
+    fn from_builder(builder: DebouncerBuilder) -> Self {
+        let DebouncerBuilder {
             cooldown,
+            mode,
+            max_wait,
+            max_batch,
+            backoff,
+            strict_leading,
+            dedup_window,
+            cooldown_from_trigger,
+            gap_after_processing,
+            fire_on_last_drop,
+            close_on_claim,
+            priority_cooldowns,
+            jitter,
+            jitter_seed,
+            clock,
+        } = builder;
+        warn_below_resolution(cooldown);
+        let rng = jitter_seed.unwrap_or_else(|| RandomState::new().build_hasher().finish());
+        let now = clock.now();
+        let inner = Arc::new(DebouncerInner {
+            notifier: Notifier::new(),
+            on_close: Notifier::new(),
+            fair: Semaphore::new(1),
+            clock,
+            max_wait,
+            max_batch,
+            backoff,
+            strict_leading,
+            dedup_window,
+            cooldown_from_trigger,
+            gap_after_processing,
+            fire_on_last_drop,
+            close_on_claim,
+            priority_cooldowns,
+            jitter,
+            triggers: AtomicU64::new(0),
+            fires: AtomicU64::new(0),
+            stats_base: [AtomicU64::new(0), AtomicU64::new(0)],
+            waiters: AtomicUsize::new(0),
             state: Mutex::new(DebouncerState {
-                has_run: if matches!(mode, DebounceMode::Leading) {
-                    false
-                } else {
-                    true
-                },
-                last_run: tokio::time::Instant::now(),
+                mode,
+                cooldown,
+                effective_cooldown: cooldown,
+                has_run: false,
+                last_run: now,
+                last_fired: None,
+                finished: None,
                 triggered: false,
+                first_trigger: None,
+                late_first_trigger: None,
+                coalesced: 0,
+                force: false,
+                closed: false,
+                paused: false,
+                claimed: false,
+                jitter: Duration::ZERO,
+                rng,
+                leading: false,
+                trailing: false,
+                intervals: IntervalStats::default(),
+                recent_fires: VecDeque::new(),
+                keys: Vec::new(),
+                claimed_keys: Vec::new(),
+                priority_deadline: None,
+                scheduled: None,
+                on_fire: None,
             }),
-            mode,
         });
-        Self { inner }
+        Self::from_inner(inner)
     }
 
-    /// Check if the debouncer is currently triggered (for diagnostics/testing).
-    pub async fn is_triggered(&self) -> bool {
+    fn from_inner(inner: Arc<DebouncerInner>) -> Self {
+        Self {
+            inner,
+            polling: Mutex::new(None),
+        }
+    }
+
+    /// The configured (base) cooldown.
+    pub fn cooldown(&self) -> Duration {
+        self.inner.state.risky_lock().cooldown
+    }
+
+    /// The cooldown currently applied. Equals [`cooldown`](Self::cooldown) unless
+    /// [`backoff`](DebouncerBuilder::backoff) has grown it after consecutive fires.
+    pub fn effective_cooldown(&self) -> Duration {
+        self.inner.state.risky_lock().effective_cooldown
+    }
+
+    /// Change the cooldown used by subsequent readiness checks, discarding any backoff.
+    /// A `ready()` that is already waiting re-evaluates its deadline with the new value.
+    pub fn set_cooldown(&self, cooldown: Duration) {
+        warn_below_resolution(cooldown);
+        {
+            let mut state = self.inner.state.risky_lock();
+            state.cooldown = cooldown;
+            state.effective_cooldown = cooldown;
+        }
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// The current debounce mode.
+    pub fn mode(&self) -> DebounceMode {
+        self.inner.state.risky_lock().mode
+    }
+
+    /// Switch the debounce mode at runtime, e.g. Leading during interactive use and Trailing
+    /// during bulk imports. A pending batch is kept and timed by the new mode's rule; callers
+    /// already waiting in `ready()` pick up the change immediately.
+    ///
+    /// Mode-specific options such as [`strict_leading`](DebouncerBuilder::strict_leading)
+    /// apply whenever the current mode is the one they are for.
+    pub fn set_mode(&self, mode: DebounceMode) {
+        {
+            let mut state = self.inner.state.risky_lock();
+            if state.mode == mode {
+                return;
+            }
+            state.mode = mode;
+            // Edge bookkeeping of the old mode means nothing to the new one
+            state.leading = false;
+            state.trailing = false;
+            state.recent_fires.clear();
+        }
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// Discard any pending trigger without firing.
+    ///
+    /// A `ready()` that is already waiting goes back to waiting for the next trigger.
+    /// The leading-mode `has_run` flag is left untouched, so the next trigger still
+    /// respects the cooldown since the last fire; use [`reset_with`](Self::reset_with) to re-arm it.
+    pub fn reset(&self) {
+        self.reset_with(false);
+    }
+
+    /// Discard any pending trigger without firing, optionally re-arming leading mode.
+    ///
+    /// With `rearm` set, `has_run` is cleared so the next trigger fires immediately in
+    /// Leading and Both modes regardless of the cooldown.
+    pub fn reset_with(&self, rearm: bool) {
+        let mut state = self.inner.state.risky_lock();
+        state.triggered = false;
+        state.first_trigger = None;
+        state.late_first_trigger = None;
+        state.coalesced = 0;
+        state.force = false;
+        state.jitter = Duration::ZERO;
+        state.leading = false;
+        state.trailing = false;
+        state.priority_deadline = None;
+        state.scheduled = None;
+        state.keys.clear();
+        if rearm {
+            state.has_run = false;
+            state.finished = None;
+        }
+        drop(state);
+        // Waiters sleeping on the discarded batch's deadline go back to waiting for a trigger
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// Re-arm the leading edge without touching pending triggers, e.g. at a session boundary.
+    ///
+    /// Clears `has_run`, so in Leading, Both and Throttle modes the next trigger fires
+    /// immediately regardless of when the last fire happened. Does not trigger by itself;
+    /// use [`reset_with(true)`](Self::reset_with) to also discard a pending trigger.
+    pub fn arm(&self) {
+        {
+            let mut state = self.inner.state.risky_lock();
+            state.has_run = false;
+            state.finished = None;
+        }
+        // A pending leading batch may have just become ready
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// Close the debouncer: further triggers are ignored, and tasks waiting in
+    /// [`ready_or_closed`](Self::ready_or_closed) resolve to `None` once nothing is pending.
+    /// A batch that is already pending is still delivered; call [`flush`](Self::flush) first
+    /// to deliver it without waiting for the cooldown.
+    pub fn close(&self) {
+        self.inner.state.risky_lock().closed = true;
+        self.inner.notifier.notify_waiters();
+        self.inner.on_close.notify_waiters();
+    }
+
+    /// Suspend firing, e.g. for a maintenance window.
+    ///
+    /// While paused, [`ready`](Self::ready) and friends never resolve, not even for a
+    /// [`flush`](Self::flush)ed batch, but triggers keep accumulating into the pending batch.
+    /// [`drain_ready`](Self::drain_ready) still takes the pending batch on request.
+    pub fn pause(&self) {
+        self.inner.state.risky_lock().paused = true;
+    }
+
+    /// Resume firing after [`pause`](Self::pause). Parked waiters re-evaluate immediately,
+    /// so a batch whose cooldown elapsed during the pause fires right away.
+    pub fn resume(&self) {
+        self.inner.state.risky_lock().paused = false;
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// Whether the debouncer is currently [paused](Self::pause).
+    pub fn is_paused(&self) -> bool {
+        self.inner.state.risky_lock().paused
+    }
+
+    /// How many tasks are currently waiting for a batch in [`ready`](Self::ready) or one of
+    /// its variants, including runner and `pipe_to` tasks. Callers queued behind
+    /// [`ready_fair`](Self::ready_fair) are counted once they reach the front.
+    ///
+    /// Useful for spotting contention or leaked waiters in multi-consumer setups.
+    pub fn waiters(&self) -> usize {
+        self.inner.waiters.load(Ordering::Relaxed)
+    }
+
+    /// Whether [`close`](Self::close) has been called.
+    pub fn is_closed(&self) -> bool {
+        self.inner.state.risky_lock().closed
+    }
+
+    /// Resolve once [`close`](Self::close) has been called, immediately if it already was.
+    ///
+    /// Unlike [`ready_or_closed`](Self::ready_or_closed) this does not wait for a pending
+    /// batch to be delivered, so a supervisor can observe shutdown of a shared debouncer.
+    pub async fn closed(&self) {
+        loop {
+            notified!(self.inner.on_close => notified);
+            if self.is_closed() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Resolve once the debouncer is quiescent: nothing is pending or being processed, and
+    /// the cooldown since the last fire has fully elapsed. Useful to synchronize tests or to
+    /// let a burst settle before shutting down. Cancel-safe.
+    pub async fn wait_idle(&self) {
+        loop {
+            notified!(self.inner.notifier => notified);
+            let deadline = {
+                let state = self.inner.state.risky_lock();
+                if state.triggered || state.claimed {
+                    None
+                } else if !state.has_run {
+                    return;
+                } else {
+                    let quiet_at = add_saturating(state.last_run, state.effective_cooldown);
+                    if self.inner.clock.now() >= quiet_at {
+                        return;
+                    }
+                    Some(quiet_at)
+                }
+            };
+            match deadline {
+                Some(deadline) => {
+                    tokio::select! {
+                        _ = self.inner.clock.sleep_until(deadline) => {}
+                        _ = notified => {}
+                    }
+                }
+                None => notified.await,
+            }
+        }
+    }
+
+    /// Make a pending trigger fire on the next `ready()` regardless of the remaining cooldown.
+    /// Does nothing if no trigger is pending.
+    ///
+    /// Useful for shutdown or manual flushes.
+    pub fn flush(&self) {
+        {
+            let mut state = self.inner.state.risky_lock();
+            if !state.triggered {
+                return;
+            }
+            state.force = true;
+        }
+        self.inner.notifier.notify_waiters();
+    }
+
+    /// When the last batch fired, or `None` if it never has.
+    ///
+    /// Unlike the internal reference point used for cooldowns (which trailing mode moves on
+    /// every trigger), this only changes when a batch is committed.
+    pub fn last_fired(&self) -> Option<Instant> {
+        self.inner.state.risky_lock().last_fired
+    }
+
+    /// How long until [`ready`](Self::ready) would resolve given the current state.
+    ///
+    /// Returns `Some(Duration::ZERO)` if a batch is eligible now, the remaining cooldown
+    /// if one is pending, and `None` if nothing is pending (or its guard is already held, or
+    /// the debouncer is paused).
+    pub fn time_until_ready(&self) -> Option<Duration> {
+        match self.status() {
+            DebouncerStatus::Ready => Some(Duration::ZERO),
+            DebouncerStatus::Pending { fires_in } => Some(fires_in),
+            DebouncerStatus::Idle | DebouncerStatus::Closed => None,
+        }
+    }
+
+    /// Snapshot of the current state, taken under a single lock acquisition.
+    ///
+    /// This is best-effort: other tasks may trigger, claim or close the debouncer
+    /// right after the snapshot is taken.
+    pub fn status(&self) -> DebouncerStatus {
         let state = self.inner.state.risky_lock();
-        state.triggered
+        let now = self.inner.clock.now();
+        match self.inner.readiness(&state, now) {
+            Readiness::Idle => DebouncerStatus::Idle,
+            Readiness::Closed => DebouncerStatus::Closed,
+            Readiness::Waiting(deadline) => DebouncerStatus::Pending {
+                fires_in: deadline.saturating_duration_since(now),
+            },
+            Readiness::Ready(_) => DebouncerStatus::Ready,
+        }
+    }
+
+    /// Register a callback invoked each time a batch is committed, replacing any previous one.
+    ///
+    /// The callback runs inside guard finalization while the state mutex is held, so it must
+    /// be fast and must not call back into this debouncer. Aborted guards do not invoke it.
+    /// With [`fire_on_last_drop`](DebouncerBuilder::fire_on_last_drop) it also runs for a batch
+    /// left pending when the last handle is dropped.
+    pub fn on_fire(&self, f: impl Fn() + Send + Sync + 'static) {
+        self.inner.state.risky_lock().on_fire = Some(Arc::new(f));
+    }
+
+    /// Stable identity shared by all clones of this debouncer (and by handles upgraded from
+    /// its [`WeakDebouncer`]s), e.g. as a registry key. Distinct debouncers alive at the same
+    /// time never share an id; an id may be reused after every handle is dropped.
+    pub fn id(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+
+    /// Whether `self` and `other` are handles to the same debouncer.
+    pub fn ptr_eq(&self, other: &Debouncer) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Create a weak handle that does not keep the debouncer alive.
+    pub fn downgrade(&self) -> WeakDebouncer {
+        WeakDebouncer {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Observe fires without consuming them: the watcher's
+    /// [`next_fire`](DebouncerWatcher::next_fire) resolves once per committed batch, while the
+    /// guard still goes to whichever task awaits `ready()`.
+    pub fn subscribe(&self) -> DebouncerWatcher {
+        DebouncerWatcher {
+            seen: self.inner.fires.load(Ordering::Relaxed),
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Trigger and fire counters since creation or the last [`reset_stats`](Self::reset_stats).
+    /// Reading them does not take the state lock.
+    pub fn stats(&self) -> DebouncerStats {
+        let [triggers_base, fires_base] = &self.inner.stats_base;
+        DebouncerStats {
+            triggers: self.inner.triggers.load(Ordering::Relaxed).wrapping_sub(triggers_base.load(Ordering::Relaxed)),
+            fires: self.inner.fires.load(Ordering::Relaxed).wrapping_sub(fires_base.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Zero the counters reported by [`stats`](Self::stats) and
+    /// [`interval_stats`](Self::interval_stats), e.g. at the start of a reporting period.
+    /// Does not affect debounce state or [`DebouncerWatcher`]s.
+    pub fn reset_stats(&self) {
+        let [triggers_base, fires_base] = &self.inner.stats_base;
+        triggers_base.store(self.inner.triggers.load(Ordering::Relaxed), Ordering::Relaxed);
+        fires_base.store(self.inner.fires.load(Ordering::Relaxed), Ordering::Relaxed);
+        self.inner.state.risky_lock().intervals = IntervalStats::default();
+    }
+
+    /// Min, max and mean time between consecutive fires, for tuning the cooldown.
+    pub fn interval_stats(&self) -> IntervalStats {
+        self.inner.state.risky_lock().intervals
+    }
+
+    /// Inspect and adjust the core debounce state under the lock, for cases the dedicated
+    /// methods don't cover.
+    ///
+    /// Setting `triggered` opens a batch starting now; clearing it discards the pending batch
+    /// like [`reset`](Self::reset). Waiters re-evaluate once the closure returns.
+    ///
+    /// The state lock is held for the whole closure, so keep it short: triggers, waiters and
+    /// guard drops on every clone block until it returns, and calling back into this
+    /// debouncer from inside the closure deadlocks. Clearing `triggered` while a guard is
+    /// held means that batch is not counted as fired when the guard drops.
+    pub fn with_state<R>(&self, f: impl FnOnce(&mut DebouncerStateView) -> R) -> R {
+        let result = {
+            let mut state = self.inner.state.risky_lock();
+            let mut view = DebouncerStateView {
+                has_run: state.has_run,
+                triggered: state.triggered,
+                last_run: state.last_run,
+                coalesced: state.coalesced,
+            };
+            let result = f(&mut view);
+            match (state.triggered, view.triggered) {
+                (false, true) => {
+                    state.first_trigger = Some(self.inner.clock.now());
+                    state.jitter = self.inner.draw_jitter(&mut state);
+                }
+                (true, false) => {
+                    state.first_trigger = None;
+                    state.late_first_trigger = None;
+                    state.force = false;
+                    state.jitter = Duration::ZERO;
+                    state.leading = false;
+                    state.trailing = false;
+                    state.priority_deadline = None;
+                    state.scheduled = None;
+                    state.keys.clear();
+                }
+                _ => {}
+            }
+            state.has_run = view.has_run;
+            state.triggered = view.triggered;
+            state.last_run = view.last_run;
+            state.coalesced = if view.triggered { view.coalesced } else { 0 };
+            result
+        };
+        self.inner.notifier.notify_waiters();
+        result
+    }
+
+    /// Check if the debouncer is currently triggered (for diagnostics/testing).
+    ///
+    /// Only takes the state lock briefly, so it is usable from sync code and `Drop`.
+    pub fn is_triggered(&self) -> bool {
+        self.inner.state.risky_lock().triggered
     }
 
     /// Trigger the debouncer. Can be called from any thread or task.
     /// Notifies the worker if not already pending.
     pub fn trigger(&self) {
-        {
+        let _ = self.trigger_inner(None, Duration::ZERO, None);
+    }
+
+    /// Like [`trigger`](Self::trigger), but fails with [`ClosedError`] instead of silently
+    /// ignoring the trigger once the debouncer is closed, so callers can detect shutdown.
+    pub fn try_trigger(&self) -> Result<(), ClosedError> {
+        self.trigger_inner(None, Duration::ZERO, None).map(drop)
+    }
+
+    /// Like [`trigger`](Self::trigger), but returns `true` if this trigger opened a new
+    /// batch and `false` if it coalesced into one already pending (or was ignored
+    /// because the debouncer is closed).
+    pub fn trigger_checked(&self) -> bool {
+        matches!(self.trigger_inner(None, Duration::ZERO, None), Ok(true))
+    }
+
+    /// Trigger with a priority. If the builder configured a
+    /// [`priority_cooldown`](DebouncerBuilder::priority_cooldown) for `priority`, the pending
+    /// batch fires no later than that cooldown after this trigger, so urgent events don't wait
+    /// behind the regular cooldown. The earliest such deadline in a burst wins. Without a
+    /// configured cooldown this is the same as [`trigger`](Self::trigger).
+    pub fn trigger_with_priority(&self, priority: Priority) {
+        let _ = self.trigger_inner(self.inner.priority_cooldowns[priority as usize], Duration::ZERO, None);
+    }
+
+    /// Trigger as if the event happened `delay` from now: in Trailing and Both modes the
+    /// cooldown window starts at `now + delay`, so the batch fires `delay + cooldown` later,
+    /// e.g. to line up with an externally scheduled event.
+    ///
+    /// A window never moves backward: if a pending trigger already reaches further out, that
+    /// deadline is kept. `max_wait` still counts from the first trigger. In Leading and
+    /// Throttle modes, where triggers don't move the window, this is the same as `trigger()`.
+    pub fn trigger_in(&self, delay: Duration) {
+        let _ = self.trigger_inner(None, delay, None);
+    }
+
+    /// Trigger and tag the pending batch with `key`, so its guard can report which keys fired
+    /// via [`keys`](DebouncerGuard::keys). Keys share this debouncer's single timer; use a
+    /// [`KeyedDebouncer`] to time each key separately.
+    ///
+    /// Each batch keeps the distinct keys in the order they first arrived, compared with a
+    /// linear scan, which suits a handful of short-lived keys. Keys are dropped when the batch
+    /// commits, and kept with the batch when it is aborted. A trigger that is ignored (closed
+    /// debouncer, strict leading, dedup window) does not record its key.
+    pub fn trigger_keyed<K: PartialEq + Send + 'static>(&self, key: K) {
+        let _ = self.trigger_inner(None, Duration::ZERO, Some(BatchKey::new(key)));
+    }
+
+    /// Record a trigger; `Ok(true)` if it opened a new batch.
+    fn trigger_inner(
+        &self,
+        priority_cooldown: Option<Duration>,
+        delay: Duration,
+        key: Option<BatchKey>,
+    ) -> Result<bool, ClosedError> {
+        let opened = {
             let mut guard = self.inner.state.risky_lock();
-            if matches!(self.inner.mode, DebounceMode::Trailing) {
-                guard.last_run = tokio::time::Instant::now();
+            if guard.closed {
+                return Err(ClosedError);
+            }
+            let mode = guard.mode;
+            let now = self.inner.clock.now();
+            if let (false, Some(_), Some(last)) = (guard.triggered, self.inner.backoff, guard.last_fired) {
+                // A batch opening after a quiet period starts over from the base cooldown
+                if now >= add_saturating(last, guard.effective_cooldown) {
+                    guard.effective_cooldown = guard.cooldown;
+                }
+            }
+            if self.inner.cooldown_from_trigger
+                && mode == DebounceMode::Leading
+                && (guard.triggered
+                    || (guard.has_run && now < add_saturating(guard.last_run, guard.effective_cooldown)))
+            {
+                // A trigger during the cooldown restarts it; one after a quiet period fires on the edge
+                guard.last_run = guard.last_run.max(now);
+            }
+            if self.inner.strict_leading
+                && mode == DebounceMode::Leading
+                && (guard.claimed
+                    || (!guard.triggered
+                        && guard.has_run
+                        && now < add_saturating(guard.last_run, guard.effective_cooldown)))
+            {
+                // Strict leading: only the first trigger of an idle period counts, so nothing
+                // arriving while its batch is processed or cooling down is kept
+                return Ok(false);
+            }
+            if mode == DebounceMode::Leading && !self.inner.dedup_window.is_zero() {
+                // The batch being processed, or else the last commit, marks the leading fire
+                let fired = if guard.claimed {
+                    guard.first_trigger
+                } else if !guard.triggered && guard.has_run {
+                    Some(guard.last_run)
+                } else {
+                    None
+                };
+                if matches!(fired, Some(fired) if now < add_saturating(fired, self.inner.dedup_window)) {
+                    return Ok(false);
+                }
+            }
+            // Only triggers that made it past the discard checks above are counted
+            self.inner.triggers.fetch_add(1, Ordering::Relaxed);
+            guard.coalesced = guard.coalesced.saturating_add(1);
+            if guard.claimed && guard.late_first_trigger.is_none() {
+                // Opens the batch left pending once the held guard is finalized
+                guard.late_first_trigger = Some(now);
+            }
+            if let Some(key) = key {
+                key.insert_into(&mut guard.keys);
+            }
+            // Never pull the window back in front of a later one set by `trigger_in`
+            let window_start = guard.last_run.max(add_saturating(now, delay));
+            match mode {
+                DebounceMode::Leading | DebounceMode::Throttle | DebounceMode::RateLimited { .. } => {}
+                DebounceMode::Trailing => guard.last_run = window_start,
+                DebounceMode::Both => {
+                    if guard.triggered {
+                        guard.trailing |= guard.leading;
+                    } else {
+                        // Only the first trigger of an idle period fires on the leading edge
+                        guard.leading = !guard.has_run
+                            || now >= add_saturating(guard.last_run, guard.effective_cooldown);
+                    }
+                    guard.last_run = window_start;
+                }
+            }
+            let mut hurried = false;
+            if let Some(cooldown) = priority_cooldown {
+                let deadline = add_saturating(now, cooldown);
+                if !matches!(guard.priority_deadline, Some(current) if current <= deadline) {
+                    guard.priority_deadline = Some(deadline);
+                    hurried = true;
+                }
             }
             if guard.triggered {
-                // Already pending, just update the value
-                return;
+                // Already pending, just update the value; only a full batch or an earlier deadline changes readiness.
+                // A later deadline needs no wakeup: a waiter sleeping on the old one re-reads the extended
+                // deadline when it wakes and sleeps again, so it never fires early, and a burst doesn't
+                // wake every waiter once per trigger.
+                if !hurried && !self.inner.batch_full(&guard) {
+                    return Ok(false);
+                }
+                false
+            } else {
+                guard.triggered = true;
+                guard.first_trigger = Some(now);
+                guard.jitter = self.inner.draw_jitter(&mut guard);
+                trace!(?mode, coalesced = guard.coalesced, "debouncer triggered");
+                true
             }
-            guard.triggered = true;
-        } // guard dropped here
-        self.inner.notifier.notify_one();
+        }; // guard dropped here
+        self.inner.notifier.notify_waiters();
+        Ok(opened)
+    }
+
+    /// Trigger the debouncer from a blocking context, e.g. inside `spawn_blocking` or a plain thread.
+    ///
+    /// Identical to [`trigger`](Self::trigger): the state lock is only held for a few field
+    /// updates and is never held across an `.await` by `ready()` or any other method, so
+    /// blocking callers cannot stall async waiters (or the reverse) for longer than that.
+    pub fn trigger_blocking(&self) {
+        self.trigger();
     }
 
     /// Wait until the debouncer is ready to run.
@@ -237,39 +1687,458 @@ impl Debouncer {
     /// # Cancel Safety
     /// This method is cancel-safe and does not change internal state until the guard is used.
     /// The debounce is committed automatically when the guard is dropped, so you do not need to call any method.
+    ///
+    /// # Multiple Consumers
+    /// Several tasks may wait on clones of the same debouncer. Every state change wakes all of
+    /// them, and exactly one receives the guard for each batch; the others keep waiting for the
+    /// next batch, which cannot become ready before the current guard is dropped.
+    /// Which waiter wins is not specified: all are woken at once and the first to be polled
+    /// claims the batch. Use [`ready_fair`](Self::ready_fair) to hand batches out in arrival order.
+    ///
+    /// Once the debouncer is closed and nothing is pending, this never resolves;
+    /// use [`ready_or_closed`](Self::ready_or_closed) to observe closure.
     pub async fn ready<'a>(&self) -> DebouncerGuard<'a> {
+        let Some(batch) = self.wait_ready().await else {
+            return std::future::pending().await;
+        };
+        DebouncerGuard::new(self.inner.clone(), batch)
+    }
+
+    /// Like [`ready`](Self::ready), but the guard borrows this handle instead of holding its
+    /// own reference to the shared state, saving a reference-count increment and decrement
+    /// per fire on hot paths with tiny cooldowns. The guard cannot outlive `self`.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, like [`ready`](Self::ready).
+    pub async fn ready_ref(&self) -> DebouncerGuard<'_> {
+        let Some(batch) = self.wait_ready().await else {
+            return std::future::pending().await;
+        };
+        DebouncerGuard::with_inner(GuardInner::Borrowed(&self.inner), batch)
+    }
+
+    /// Like [`ready`](Self::ready), but batches go to waiters in FIFO order: the task that has
+    /// been waiting longest receives the next batch. Worker pools that loop on this rotate
+    /// through their workers instead of one worker winning every race.
+    ///
+    /// Only callers of `ready_fair` queue up; a concurrent plain `ready()` can still take a batch
+    /// first. Cancel-safe: a cancelled call leaves the queue without affecting others.
+    pub async fn ready_fair<'a>(&self) -> DebouncerGuard<'a> {
+        // The semaphore queues acquirers in FIFO order; the permit is held only while waiting
+        let _turn = self.inner.fair.acquire().await.expect("fairness semaphore is never closed");
+        self.ready().await
+    }
+
+    /// Trigger, then wait for the resulting batch: `trigger()` followed by `ready().await`.
+    ///
+    /// In Leading, Both and Throttle modes this resolves immediately if the debouncer is idle
+    /// and the cooldown since the last fire has elapsed; in Trailing mode it always waits a
+    /// full cooldown, restarted by any further triggers from other tasks.
+    pub async fn trigger_and_wait<'a>(&self) -> DebouncerGuard<'a> {
+        self.trigger();
+        self.ready().await
+    }
+
+    /// Like [`ready`](Self::ready), but decides readiness with `mode` instead of the configured
+    /// mode, for this call only. E.g. `ready_as(DebounceMode::Leading)` on a trailing debouncer
+    /// fires the first event after startup immediately.
+    ///
+    /// Only the readiness rule is overridden; triggers and finalization still follow the
+    /// configured mode. Concurrent waiters using different modes on the same debouncer may
+    /// therefore interact surprisingly, e.g. one waiter's early fire resets the other's window.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`ready`](Self::ready).
+    pub async fn ready_as<'a>(&self, mode: DebounceMode) -> DebouncerGuard<'a> {
+        let Some(batch) = self.wait_ready_as(Some(mode)).await else {
+            return std::future::pending().await;
+        };
+        DebouncerGuard::new(self.inner.clone(), batch)
+    }
+
+    /// Like [`ready`](Self::ready), but also returns a [`BatchInfo`] describing the batch:
+    /// how many triggers it coalesced, when the first arrived and when it became ready.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`ready`](Self::ready).
+    pub async fn ready_with_info<'a>(&self) -> (DebouncerGuard<'a>, BatchInfo) {
+        let Some(batch) = self.wait_ready().await else {
+            return std::future::pending().await;
+        };
+        (DebouncerGuard::new(self.inner.clone(), batch), batch)
+    }
+
+    /// Like [`ready`](Self::ready), but resolves to `None` once the debouncer is closed
+    /// and no batch is pending. A batch pending at close time is still delivered first.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`ready`](Self::ready).
+    pub async fn ready_or_closed<'a>(&self) -> Option<DebouncerGuard<'a>> {
+        let batch = self.wait_ready().await?;
+        Some(DebouncerGuard::new(self.inner.clone(), batch))
+    }
+
+    /// Like [`ready`](Self::ready), but returns an [`OwnedDebouncerGuard`] that is `Send + 'static`
+    /// and can be handed to another task for processing.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`ready`](Self::ready).
+    pub async fn ready_owned(&self) -> OwnedDebouncerGuard {
+        let Some(batch) = self.wait_ready().await else {
+            return std::future::pending().await;
+        };
+        self.owned_guard(batch)
+    }
+
+    fn owned_guard(&self, batch: BatchInfo) -> OwnedDebouncerGuard {
+        OwnedDebouncerGuard {
+            inner: self.inner.clone(),
+            completed: false,
+            batch,
+        }
+    }
+
+    /// Like [`ready`](Self::ready), but gives up after `dur`.
+    /// Returns `None` if the debouncer did not become ready in time.
+    ///
+    /// # Cancel Safety
+    /// Timing out leaves the state untouched; a pending trigger stays pending.
+    pub async fn ready_timeout<'a>(&self, dur: Duration) -> Option<DebouncerGuard<'a>> {
+        let deadline = add_saturating(self.inner.clock.now(), dur);
+        tokio::select! {
+            biased;
+            guard = self.ready() => Some(guard),
+            _ = self.inner.clock.sleep_until(deadline) => None,
+        }
+    }
+
+    /// Like [`ready`](Self::ready), but resolves to `None` if `token` is cancelled first
+    /// (requires the `cancellation` feature).
+    ///
+    /// # Cancel Safety
+    /// Cancellation does not commit a batch; a pending trigger stays pending.
+    #[cfg(feature = "cancellation")]
+    pub async fn ready_with_cancel<'a>(
+        &self,
+        token: &tokio_util::sync::CancellationToken,
+    ) -> Option<DebouncerGuard<'a>> {
+        tokio::select! {
+            biased;
+            _ = token.cancelled() => None,
+            guard = self.ready() => Some(guard),
+        }
+    }
+
+    /// Poll-based [`ready`](Self::ready), for embedding in a hand-written `Future`.
+    ///
+    /// Returns `Poll::Ready` with the guard once a batch may fire; otherwise registers
+    /// `cx`'s waker to be woken when the state changes or the cooldown elapses.
+    /// The wait in progress is stored in this handle, so poll a given handle from one
+    /// place at a time and use clones for independent waiters.
+    ///
+    /// # Cancel Safety
+    /// Dropping the handle or simply not polling again leaves the state untouched,
+    /// as with [`ready`](Self::ready).
+    pub fn poll_ready<'a>(&self, cx: &mut Context<'_>) -> Poll<DebouncerGuard<'a>> {
+        let mut polling = self.polling.risky_lock();
+        let wait = polling.get_or_insert_with(|| {
+            let debouncer = self.clone();
+            Box::pin(async move { debouncer.wait_ready().await })
+        });
+        match wait.as_mut().poll(cx) {
+            Poll::Ready(Some(batch)) => {
+                *polling = None;
+                Poll::Ready(DebouncerGuard::new(self.inner.clone(), batch))
+            }
+            // Closed with nothing pending: never resolves, like `ready()`
+            Poll::Ready(None) => {
+                *polling = Some(Box::pin(std::future::pending()));
+                Poll::Pending
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Wait until the pending batch may fire and claim it,
+    /// or `None` once closed with nothing pending.
+    async fn wait_ready(&self) -> Option<BatchInfo> {
+        self.wait_ready_as(None).await
+    }
+
+    /// Like `wait_ready`, but applying `mode`'s readiness rule if given instead of the current mode's.
+    async fn wait_ready_as(&self, mode: Option<DebounceMode>) -> Option<BatchInfo> {
         // Do not change state here to keep it cancel-safe for use inside select
+        let _waiting = WaiterCount::enter(&self.inner.waiters);
         loop {
-            let notified = self.inner.notifier.notified();
-            {
-                let state = self.inner.state.risky_lock();
-                if !state.triggered {
-                    drop(state);
-                    notified.await;
-                    continue;
-                }
-                let now = tokio::time::Instant::now();
-                let next_allowed = state.last_run + self.inner.cooldown;
-                match self.inner.mode {
-                    DebounceMode::Leading => {
-                        if !state.has_run || now >= next_allowed {
-                            break;
-                        } else {
-                            drop(state);
-                            tokio::time::sleep_until(next_allowed).await;
-                        }
+            notified!(self.inner.notifier => notified);
+            let deadline = {
+                let mut state = self.inner.state.risky_lock();
+                let mode = mode.unwrap_or(state.mode);
+                match self.inner.readiness_as(&state, self.inner.clock.now(), mode) {
+                    // No await follows the claim, so this cannot be cancelled before the guard exists
+                    Readiness::Ready(ready_at) => return Some(self.inner.claim(&mut state, ready_at)),
+                    Readiness::Closed => return None,
+                    Readiness::Waiting(deadline) => {
+                        debug!(
+                            ?mode,
+                            coalesced = state.coalesced,
+                            ?deadline,
+                            "debouncer waiting for cooldown"
+                        );
+                        Some(deadline)
                     }
-                    DebounceMode::Trailing => {
-                        if now >= next_allowed {
-                            break;
-                        } else {
-                            drop(state);
-                            tokio::time::sleep_until(next_allowed).await;
-                        }
+                    Readiness::Idle => None,
+                }
+            };
+            // The state lock is released before awaiting
+            match deadline {
+                Some(deadline) => {
+                    // Wake early if the state changes, e.g. the cooldown was shortened
+                    tokio::select! {
+                        _ = self.inner.clock.sleep_until(deadline) => {}
+                        _ = notified => {}
                     }
                 }
+                None => notified.await,
+            }
+        }
+    }
+
+    /// Spawn a task that sends `()` into `tx` once per batch, instead of awaiting `ready()`.
+    ///
+    /// The batch is committed after the message is accepted, so a full channel applies
+    /// backpressure and further triggers coalesce meanwhile. The task ends once the receiver
+    /// is dropped or the debouncer is closed with nothing pending. Must be called within a
+    /// Tokio runtime.
+    pub fn pipe_to(&self, tx: mpsc::Sender<()>) -> JoinHandle<()> {
+        let debouncer = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let batch = tokio::select! {
+                    _ = tx.closed() => return,
+                    batch = debouncer.wait_ready() => match batch {
+                        Some(batch) => batch,
+                        None => return,
+                    },
+                };
+                let _guard = debouncer.owned_guard(batch);
+                if tx.send(()).await.is_err() {
+                    return;
+                }
+            }
+        })
+    }
+
+    /// Spawn a task that triggers once each time the value in `rx` changes, e.g. to debounce
+    /// reactions to config reloads.
+    ///
+    /// The value already in the channel when this is called does not trigger. The task ends
+    /// once the watch sender is dropped or the debouncer is closed. Must be called within a
+    /// Tokio runtime.
+    pub fn trigger_on_watch<T>(&self, mut rx: watch::Receiver<T>) -> JoinHandle<()>
+    where
+        T: Send + Sync + 'static,
+    {
+        let debouncer = self.clone();
+        tokio::spawn(async move {
+            while rx.changed().await.is_ok() {
+                if debouncer.try_trigger().is_err() {
+                    return;
+                }
+            }
+        })
+    }
+
+    /// Spawn a task that waits for `signal`, then [`flush`](Self::flush)es any pending batch and
+    /// [`close`](Self::close)s the debouncer, wiring it into an application-wide shutdown.
+    ///
+    /// Waiters in [`ready_or_closed`](Self::ready_or_closed) receive the final batch at once
+    /// and then resolve to `None`. The task only holds a weak handle, so it does not keep the
+    /// debouncer alive; if every handle is gone by the time the signal resolves it does
+    /// nothing. Must be called within a Tokio runtime.
+    pub fn install_shutdown(&self, signal: impl Future<Output = ()> + Send + 'static) -> JoinHandle<()> {
+        let debouncer = self.downgrade();
+        tokio::spawn(async move {
+            signal.await;
+            if let Some(debouncer) = debouncer.upgrade() {
+                debouncer.flush();
+                debouncer.close();
+            }
+        })
+    }
+
+    /// Convert the debouncer into a [`Stream`](futures_core::Stream) of guards,
+    /// one per batch (requires the `stream` feature).
+    #[cfg(feature = "stream")]
+    pub fn into_stream(self) -> DebouncerStream {
+        DebouncerStream::new(self)
+    }
+
+    /// Debounce an `mpsc` receiver into a [`Stream`](futures_core::Stream) of batches
+    /// (requires the `stream` feature).
+    ///
+    /// Every received item is queued; each time the debouncer fires, the queued items are
+    /// emitted together as one `Vec`. When all senders are dropped, the remaining items are
+    /// flushed immediately and the stream then ends.
+    ///
+    /// ```rust
+    /// use futures_util::StreamExt;
+    /// use tokio::sync::mpsc;
+    /// use tokio::time::Duration;
+    /// use tokio_debouncer::{DebounceMode, Debouncer};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let (tx, rx) = mpsc::channel(16);
+    /// for n in 1..=3 {
+    ///     tx.send(n).await.unwrap();
+    /// }
+    /// drop(tx);
+    /// let batches: Vec<_> = Debouncer::from_receiver(rx, Duration::from_millis(100), DebounceMode::Trailing)
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(batches, vec![vec![1, 2, 3]]);
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn from_receiver<T: 'static>(
+        rx: mpsc::Receiver<T>,
+        cooldown: Duration,
+        mode: DebounceMode,
+    ) -> DebouncedReceiver<T> {
+        DebouncedReceiver::new(rx, cooldown, mode)
+    }
+
+    /// Take the pending batch right now, ignoring the cooldown.
+    /// Returns `None` if nothing is pending or its guard is already held.
+    ///
+    /// Useful at shutdown to process whatever is left without waiting.
+    pub fn drain_ready<'a>(&self) -> Option<DebouncerGuard<'a>> {
+        let batch = self.drain_batch()?;
+        Some(DebouncerGuard::new(self.inner.clone(), batch))
+    }
+
+    /// Claim the pending batch regardless of the cooldown, if it isn't already claimed.
+    fn drain_batch(&self) -> Option<BatchInfo> {
+        let mut state = self.inner.state.risky_lock();
+        if !state.triggered || state.claimed {
+            return None;
+        }
+        Some(self.inner.claim(&mut state, self.inner.clock.now()))
+    }
+
+    /// Check readiness without waiting.
+    /// Returns a guard if the debouncer is triggered and its cooldown has elapsed, `None` otherwise.
+    ///
+    /// Useful inside custom poll loops that already own a timer.
+    pub fn try_ready<'a>(&self) -> Option<DebouncerGuard<'a>> {
+        let mut state = self.inner.state.risky_lock();
+        match self.inner.readiness(&state, self.inner.clock.now()) {
+            Readiness::Ready(ready_at) => {
+                let batch = self.inner.claim(&mut state, ready_at);
+                Some(DebouncerGuard::new(self.inner.clone(), batch))
             }
+            _ => None,
         }
-        DebouncerGuard::new(self.inner.clone())
+    }
+}
+
+/// Weak handle to a [`Debouncer`], created by [`Debouncer::downgrade`].
+///
+/// Useful as a trigger source that should not keep the debouncer alive once its owner exits.
+#[derive(Clone)]
+pub struct WeakDebouncer {
+    inner: Weak<DebouncerInner>,
+}
+
+impl WeakDebouncer {
+    /// Upgrade to a strong handle, or `None` if every `Debouncer` has been dropped.
+    pub fn upgrade(&self) -> Option<Debouncer> {
+        self.inner.upgrade().map(Debouncer::from_inner)
+    }
+
+    /// Trigger the debouncer if it is still alive; a no-op otherwise.
+    pub fn trigger(&self) {
+        if let Some(debouncer) = self.upgrade() {
+            debouncer.trigger();
+        }
+    }
+}
+
+/// Fire observer created by [`Debouncer::subscribe`].
+///
+/// It never touches debounce state or competes for guards. It keeps the debouncer alive.
+pub struct DebouncerWatcher {
+    inner: Arc<DebouncerInner>,
+    /// Fires already reported, compared against the lifetime fire counter.
+    seen: u64,
+}
+
+impl DebouncerWatcher {
+    /// Wait until the next batch is committed after the previous call (or after `subscribe()`).
+    ///
+    /// Fires that happen while the watcher is not being awaited are not lost: each one
+    /// resolves a later call. Cancel-safe.
+    pub async fn next_fire(&mut self) {
+        loop {
+            notified!(self.inner.notifier => notified);
+            if self.inner.fires.load(Ordering::Relaxed) > self.seen {
+                self.seen += 1;
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl fmt::Debug for DebouncerWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebouncerWatcher")
+            .field("seen", &self.seen)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn counters_near_overflow_do_not_panic() {
+        // Test: Counters at u64::MAX saturate or wrap instead of panicking
+        let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+        debounce.trigger();
+        debounce.inner.triggers.store(u64::MAX, Ordering::Relaxed);
+        debounce.inner.fires.store(u64::MAX, Ordering::Relaxed);
+        debounce.inner.state.risky_lock().coalesced = u64::MAX;
+
+        debounce.trigger();
+        let guard = debounce.ready().await;
+        assert_eq!(guard.coalesced_count(), u64::MAX);
+        drop(guard);
+        assert_eq!(debounce.stats(), DebouncerStats { triggers: 0, fires: 0 });
+
+        debounce.reset_stats();
+        debounce.trigger();
+        assert_eq!(debounce.stats(), DebouncerStats { triggers: 1, fires: 0 });
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn ready_ref_does_not_clone_the_arc() {
+        // Test: A guard from ready_ref borrows the state; one from ready holds its own reference
+        let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Leading);
+        let handles = Arc::strong_count(&debounce.inner);
+
+        debounce.trigger();
+        let guard = debounce.ready_ref().await;
+        assert_eq!(Arc::strong_count(&debounce.inner), handles);
+        drop(guard);
+        assert_eq!(debounce.stats().fires, 1, "the borrowed guard commits on drop");
+
+        debounce.trigger();
+        let guard = debounce.ready().await;
+        assert_eq!(Arc::strong_count(&debounce.inner), handles + 1);
+        drop(guard);
+        assert_eq!(Arc::strong_count(&debounce.inner), handles);
     }
 }
@@ -0,0 +1,56 @@
+//! Wakeup primitive used by the debouncer.
+//!
+//! By default this is `tokio::sync::Notify`. With the `event-listener` feature it is
+//! `event_listener::Event`, which keeps waiter bookkeeping in the listener itself instead
+//! of a shared intrusive list. Both behave the same for our purposes: a listener created
+//! before `notify_waiters` is woken by it, even if it has not been polled yet.
+//!
+//! Listeners are created with [`notified!`], which pins them on the waiting task's stack,
+//! so waiting never allocates with either backend.
+
+#[derive(Default)]
+pub(crate) struct Notifier {
+    #[cfg(not(feature = "event-listener"))]
+    inner: tokio::sync::Notify,
+    #[cfg(feature = "event-listener")]
+    inner: event_listener::Event,
+}
+
+impl Notifier {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// The underlying primitive, for [`notified!`].
+    #[cfg(not(feature = "event-listener"))]
+    pub(crate) fn raw(&self) -> &tokio::sync::Notify {
+        &self.inner
+    }
+
+    /// The underlying primitive, for [`notified!`].
+    #[cfg(feature = "event-listener")]
+    pub(crate) fn raw(&self) -> &event_listener::Event {
+        &self.inner
+    }
+
+    /// Wake every registered listener.
+    pub(crate) fn notify_waiters(&self) {
+        #[cfg(not(feature = "event-listener"))]
+        self.inner.notify_waiters();
+        #[cfg(feature = "event-listener")]
+        self.inner.notify(usize::MAX);
+    }
+}
+
+/// Register interest in the next notification of a [`Notifier`], binding a listener pinned
+/// on the stack to `$name`. The listener is a future that resolves once notified.
+macro_rules! notified {
+    ($notifier:expr => $name:ident) => {
+        #[cfg(not(feature = "event-listener"))]
+        #[allow(unused_mut)]
+        let mut $name = std::pin::pin!($notifier.raw().notified());
+        #[cfg(feature = "event-listener")]
+        event_listener::listener!(*$notifier.raw() => $name);
+    };
+}
+pub(crate) use notified;
@@ -0,0 +1,100 @@
+//! Debouncer that fires a single time.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::time::Duration;
+
+use crate::{DebounceMode, Debouncer, DebouncerBuilder, DebouncerGuard};
+
+/// Debouncer that fires exactly once, e.g. to coalesce a burst of startup events.
+///
+/// Before its fire it times batches like a [`Debouncer`] with the same mode. Handing out the
+/// batch closes the debouncer under the same lock acquisition, so no trigger can join it
+/// late and no second guard is ever handed out. From then on it is permanently done:
+/// [`ready`](Self::ready) resolves to `None` and [`trigger`](Self::trigger) is a no-op, both
+/// checked with a single atomic load instead of the state lock once the fire has been seen.
+///
+/// Can be cloned and shared between tasks.
+#[derive(Clone, Debug)]
+pub struct OneShotDebouncer {
+    debouncer: Debouncer,
+    fired: Arc<AtomicBool>,
+}
+
+impl OneShotDebouncer {
+    /// Create a one-shot debouncer with a cooldown time and mode.
+    pub fn new(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self {
+            debouncer: Debouncer::from_builder(DebouncerBuilder {
+                close_on_claim: true,
+                ..DebouncerBuilder::new().cooldown(cooldown).mode(mode)
+            }),
+            fired: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Create a latch: all triggers up to the first quiet period of `cooldown` coalesce into
+    /// a single trailing fire, and every later trigger is ignored. Handy for one-time
+    /// initialization once startup activity has settled.
+    pub fn latch(cooldown: Duration) -> Self {
+        Self::new(cooldown, DebounceMode::Trailing)
+    }
+
+    /// Trigger the debouncer; ignored once it has fired.
+    pub fn trigger(&self) {
+        if !self.is_fired() {
+            self.debouncer.trigger();
+        }
+    }
+
+    /// Whether the single batch has been handed out.
+    pub fn is_fired(&self) -> bool {
+        self.fired.load(Ordering::Acquire)
+    }
+
+    /// Wait for the single batch: `Some(guard)` the first time, `None` ever after.
+    ///
+    /// If several tasks wait at once, exactly one receives the guard and the others resolve
+    /// to `None`.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`Debouncer::ready`].
+    pub async fn ready<'a>(&self) -> Option<DebouncerGuard<'a>> {
+        if self.is_fired() {
+            return None;
+        }
+        // Claiming the batch has already closed the debouncer, turning away later triggers
+        let guard = self.debouncer.ready_or_closed().await?;
+        self.fired.store(true, Ordering::Release);
+        // Wake tasks in `closed()`, which the claim itself does not notify
+        self.debouncer.close();
+        Some(guard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn trigger_racing_the_fire_is_turned_away() {
+        // Test: A trigger landing after the claim but before `fired` is set creates no late batch
+        let one_shot = OneShotDebouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+        one_shot.trigger();
+
+        // Claim through the inner debouncer, leaving `fired` unset as in the race window
+        let guard = one_shot.debouncer.ready_or_closed().await.expect("first batch");
+        let waiter = {
+            let one_shot = one_shot.clone();
+            tokio::spawn(async move { one_shot.ready().await.is_some() })
+        };
+        tokio::task::yield_now().await;
+        assert!(!one_shot.is_fired());
+        one_shot.trigger();
+        assert!(one_shot.debouncer.is_closed());
+        drop(guard);
+
+        assert!(!one_shot.debouncer.is_triggered());
+        assert!(!waiter.await.unwrap(), "no second guard is handed out");
+    }
+}
@@ -0,0 +1,95 @@
+//! Worker loop driving a batch handler (requires the `runner` feature).
+
+use std::future::Future;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::Debouncer;
+
+/// Owns a [`Debouncer`] and an async handler, and runs the handler once per batch.
+///
+/// This is the `loop { select! { ready() => ... } }` worker pattern from the docs,
+/// packaged up. The guard is held while the handler runs and dropped afterwards,
+/// so triggers arriving during processing are coalesced into the next batch.
+///
+/// ```rust
+/// use tokio_debouncer::{DebounceMode, Debouncer, DebouncerRunner};
+/// use tokio_util::sync::CancellationToken;
+/// use tokio::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let debouncer = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+/// let cancel = CancellationToken::new();
+/// let runner = DebouncerRunner::new(debouncer.clone(), || async {
+///     println!("Processing job batch!");
+/// });
+/// let worker = tokio::spawn(runner.run(cancel.clone()));
+///
+/// debouncer.trigger();
+/// cancel.cancel();
+/// worker.await.unwrap();
+/// # }
+/// ```
+pub struct DebouncerRunner<F> {
+    debouncer: Debouncer,
+    handler: F,
+    drain_on_stop: bool,
+}
+
+impl<F, Fut> DebouncerRunner<F>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    /// Create a runner invoking `handler` once per batch of `debouncer`.
+    pub fn new(debouncer: Debouncer, handler: F) -> Self {
+        Self {
+            debouncer,
+            handler,
+            drain_on_stop: false,
+        }
+    }
+
+    /// On cancellation, run the handler one last time for a pending batch, skipping its
+    /// remaining cooldown, before `run()` returns, so queued work is not lost at shutdown.
+    /// Off by default: a pending batch is left untouched.
+    pub fn run_until_idle_on_stop(mut self, enabled: bool) -> Self {
+        self.drain_on_stop = enabled;
+        self
+    }
+
+    /// The debouncer driving this runner; clone it to trigger from elsewhere.
+    pub fn debouncer(&self) -> &Debouncer {
+        &self.debouncer
+    }
+
+    /// Run the handler once per batch until `cancel` fires or the debouncer is closed.
+    ///
+    /// Cancellation is checked between batches: a handler that is already running
+    /// is allowed to finish, and its batch is committed.
+    pub async fn run(mut self, cancel: CancellationToken) {
+        loop {
+            let guard = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => {
+                    if self.drain_on_stop {
+                        if let Some(batch) = self.debouncer.drain_batch() {
+                            let guard = self.debouncer.owned_guard(batch);
+                            (self.handler)().await;
+                            drop(guard);
+                        }
+                    }
+                    return;
+                }
+                batch = self.debouncer.wait_ready() => match batch {
+                    // Owned so the run() future stays `Send` while the handler is awaited
+                    Some(batch) => self.debouncer.owned_guard(batch),
+                    None => return,
+                },
+            };
+            (self.handler)().await;
+            drop(guard);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! `Stream` adapter for the debouncer (requires the `stream` feature).
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+use crate::{DebounceMode, Debouncer, DebouncerGuard, QueueDebouncer, ValueDebouncer};
+
+type ReadyFuture = Pin<Box<dyn Future<Output = DebouncerGuard<'static>> + Send>>;
+
+/// Stream yielding a [`DebouncerGuard`] each time the debouncer becomes ready.
+///
+/// Created by [`Debouncer::into_stream`]. The stream never ends; drop each guard before
+/// polling for the next batch so the debounce state is finalized in between.
+pub struct DebouncerStream {
+    debouncer: Debouncer,
+    pending: Option<ReadyFuture>,
+}
+
+impl DebouncerStream {
+    pub(crate) fn new(debouncer: Debouncer) -> Self {
+        Self {
+            debouncer,
+            pending: None,
+        }
+    }
+}
+
+impl Stream for DebouncerStream {
+    type Item = DebouncerGuard<'static>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let pending = this.pending.get_or_insert_with(|| {
+            let debouncer = this.debouncer.clone();
+            Box::pin(async move { debouncer.ready().await })
+        });
+        match pending.as_mut().poll(cx) {
+            Poll::Ready(guard) => {
+                this.pending = None;
+                Poll::Ready(Some(guard))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Debounce an existing stream: emit the last item of each burst once the burst has
+/// settled according to `cooldown` and `mode` (requires the `stream` feature).
+///
+/// Every upstream item is fed to a last-write-wins [`ValueDebouncer`]. When the upstream
+/// ends, a pending item is flushed immediately and the debounced stream then ends too.
+///
+/// ```rust
+/// use futures_util::{stream, StreamExt};
+/// use tokio_debouncer::{debounce_stream, DebounceMode};
+/// use tokio::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let events = stream::iter(vec![1, 2, 3]);
+/// let settled: Vec<_> = debounce_stream(events, Duration::from_millis(100), DebounceMode::Trailing)
+///     .collect()
+///     .await;
+/// assert_eq!(settled, vec![3]);
+/// # }
+/// ```
+pub fn debounce_stream<S>(stream: S, cooldown: Duration, mode: DebounceMode) -> DebouncedStream<S>
+where
+    S: Stream,
+    S::Item: 'static,
+{
+    DebouncedStream {
+        upstream: Some(Box::pin(stream)),
+        values: ValueDebouncer::new(cooldown, mode),
+    }
+}
+
+/// Stream returned by [`debounce_stream`].
+pub struct DebouncedStream<S: Stream> {
+    /// `None` once the upstream has ended.
+    upstream: Option<Pin<Box<S>>>,
+    values: ValueDebouncer<S::Item>,
+}
+
+impl<S> Stream for DebouncedStream<S>
+where
+    S: Stream,
+    S::Item: 'static,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        // Feed everything the upstream has ready into the debouncer
+        while let Some(upstream) = this.upstream.as_mut() {
+            match upstream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => this.values.trigger_with(item),
+                Poll::Ready(None) => {
+                    this.upstream = None;
+                    this.values.debouncer().flush();
+                }
+                Poll::Pending => break,
+            }
+        }
+        let debouncer = this.values.debouncer();
+        if this.upstream.is_none() && !debouncer.is_triggered() {
+            return Poll::Ready(None);
+        }
+        match debouncer.poll_ready(cx) {
+            Poll::Ready(guard) => {
+                let mut guard = this.values.value_guard(guard);
+                Poll::Ready(guard.take())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Stream returned by [`Debouncer::from_receiver`], yielding one `Vec` per batch.
+pub struct DebouncedReceiver<T> {
+    /// `None` once every sender has been dropped.
+    rx: Option<mpsc::Receiver<T>>,
+    values: QueueDebouncer<T>,
+}
+
+impl<T: 'static> DebouncedReceiver<T> {
+    pub(crate) fn new(rx: mpsc::Receiver<T>, cooldown: Duration, mode: DebounceMode) -> Self {
+        Self {
+            rx: Some(rx),
+            values: ValueDebouncer::queue(cooldown, mode),
+        }
+    }
+}
+
+impl<T: 'static> Stream for DebouncedReceiver<T> {
+    type Item = Vec<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        // Drain everything already sitting in the channel into the queue
+        while let Some(rx) = this.rx.as_mut() {
+            match rx.poll_recv(cx) {
+                Poll::Ready(Some(item)) => this.values.trigger_with(item),
+                Poll::Ready(None) => {
+                    this.rx = None;
+                    this.values.debouncer().flush();
+                }
+                Poll::Pending => break,
+            }
+        }
+        let debouncer = this.values.debouncer();
+        if this.rx.is_none() && !debouncer.is_triggered() {
+            return Poll::Ready(None);
+        }
+        match debouncer.poll_ready(cx) {
+            Poll::Ready(guard) => {
+                let mut guard = this.values.value_guard(guard);
+                Poll::Ready(Some(guard.drain().collect()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Payload-carrying debouncer.
+
+use std::collections::{hash_set, vec_deque, HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Duration;
+
+use crate::{BuildError, DebounceMode, Debouncer, DebouncerGuard, Mutex, MutexExt};
+
+/// Debouncer for events that carry data.
+///
+/// Each `trigger_with()` merges its value into an accumulator of type `A`, and the guard
+/// returned by `ready()` hands the accumulated batch out via `take()`. The merge policy is
+/// picked by the constructor:
+/// - [`new`](ValueDebouncer::new): last-write-wins, `A = Option<T>`.
+/// - [`queue`](ValueDebouncer::queue): every value in arrival order, `A = VecDeque<T>`.
+/// - [`set`](ValueDebouncer::set): every distinct value, `A = HashSet<T>`.
+/// - [`fold`](ValueDebouncer::fold): a custom reducer over any accumulator.
+///
+/// Can be cloned and shared between tasks.
+pub struct ValueDebouncer<T, A = Option<T>> {
+    debouncer: Debouncer,
+    acc: Arc<Accumulator<T, A>>,
+}
+
+/// A [`ValueDebouncer`] that queues every value of a batch, see [`ValueDebouncer::queue`].
+pub type QueueDebouncer<T> = ValueDebouncer<T, VecDeque<T>>;
+
+/// Merges a value into the accumulator; returns `true` when the batch should fire right away.
+type MergeFn<T, A> = Box<dyn Fn(&mut A, T) -> bool + Send + Sync>;
+
+/// A [`ValueDebouncer`] that collects the distinct values of a batch, see [`ValueDebouncer::set`].
+pub type SetDebouncer<T> = ValueDebouncer<T, HashSet<T>>;
+
+/// The accumulated payload of the pending batch and how values are merged into it.
+struct Accumulator<T, A> {
+    slot: Mutex<A>,
+    empty: Box<dyn Fn() -> A + Send + Sync>,
+    merge: MergeFn<T, A>,
+}
+
+impl<T, A> Accumulator<T, A> {
+    /// Swap the payload out for a fresh empty one.
+    ///
+    /// `absorb` runs under the slot lock, so every trigger it sees has already merged its value
+    /// into the payload being taken.
+    fn take(&self, absorb: impl FnOnce()) -> A {
+        let mut slot = self.slot.risky_lock();
+        absorb();
+        std::mem::replace(&mut *slot, (self.empty)())
+    }
+}
+
+impl<T, A> Clone for ValueDebouncer<T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            debouncer: self.debouncer.clone(),
+            acc: self.acc.clone(),
+        }
+    }
+}
+
+impl<T: 'static> ValueDebouncer<T> {
+    /// Create a new ValueDebouncer with a cooldown time and mode.
+    /// A burst keeps only its last value (last-write-wins).
+    pub fn new(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self::from_parts(cooldown, mode, || None, |slot, value| *slot = Some(value))
+    }
+}
+
+impl<T: 'static> ValueDebouncer<T, VecDeque<T>> {
+    /// Create a ValueDebouncer that queues every value of a burst.
+    /// The guard yields them in insertion (FIFO) order via [`drain`](ValueGuard::drain).
+    pub fn queue(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self::from_parts(cooldown, mode, VecDeque::new, VecDeque::push_back)
+    }
+}
+
+/// What a bounded [`QueueDebouncer`] does with a value that arrives when the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Evict the oldest queued value to make room.
+    #[default]
+    DropOldest,
+    /// Discard the incoming value; the trigger still counts.
+    DropNewest,
+    /// Keep the value and fire the batch immediately, bypassing the cooldown. The queue can
+    /// briefly exceed the capacity until the worker takes it.
+    FireNow,
+}
+
+/// Builder for a [`QueueDebouncer`] with a bounded queue.
+///
+/// ```rust
+/// use tokio_debouncer::{DebounceMode, OverflowPolicy, QueueDebouncer, QueueDebouncerBuilder};
+/// use tokio::time::Duration;
+///
+/// let debouncer: QueueDebouncer<u32> = QueueDebouncerBuilder::new(Duration::from_millis(100), DebounceMode::Trailing)
+///     .capacity(1024)
+///     .overflow(OverflowPolicy::FireNow)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueueDebouncerBuilder {
+    cooldown: Duration,
+    mode: DebounceMode,
+    capacity: Option<usize>,
+    overflow: OverflowPolicy,
+}
+
+impl QueueDebouncerBuilder {
+    /// Start a queue debouncer with the given cooldown and mode, unbounded by default.
+    pub fn new(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self {
+            cooldown,
+            mode,
+            capacity: None,
+            overflow: OverflowPolicy::default(),
+        }
+    }
+
+    /// Hold at most `capacity` values per batch. Must be at least 1.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// What to do once the queue is full; [`OverflowPolicy::DropOldest`] by default.
+    pub fn overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Validate the configuration and build the debouncer.
+    pub fn build<T: 'static>(self) -> Result<QueueDebouncer<T>, BuildError> {
+        let Some(capacity) = self.capacity else {
+            return Ok(ValueDebouncer::queue(self.cooldown, self.mode));
+        };
+        if capacity == 0 {
+            return Err(BuildError::ZeroCapacity);
+        }
+        let overflow = self.overflow;
+        let merge = move |queue: &mut VecDeque<T>, value: T| {
+            if queue.len() < capacity {
+                queue.push_back(value);
+                return overflow == OverflowPolicy::FireNow && queue.len() >= capacity;
+            }
+            match overflow {
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                    queue.push_back(value);
+                    false
+                }
+                OverflowPolicy::DropNewest => false,
+                OverflowPolicy::FireNow => {
+                    queue.push_back(value);
+                    true
+                }
+            }
+        };
+        Ok(ValueDebouncer::from_merge(self.cooldown, self.mode, VecDeque::new, merge))
+    }
+}
+
+impl<T: Hash + Eq + 'static> ValueDebouncer<T, HashSet<T>> {
+    /// Create a ValueDebouncer that collects the distinct values of a burst,
+    /// e.g. the ids of every region touched. Duplicates are merged.
+    pub fn set(cooldown: Duration, mode: DebounceMode) -> Self {
+        Self::from_parts(cooldown, mode, HashSet::new, |set, value| {
+            set.insert(value);
+        })
+    }
+}
+
+impl<T: 'static, A: Clone + Send + Sync + 'static> ValueDebouncer<T, A> {
+    /// Create a ValueDebouncer that reduces a burst with `f`, starting each batch from `init`,
+    /// e.g. to sum numeric deltas or keep the maximum.
+    ///
+    /// `f` runs under a lock inside `trigger_with()`, so it must be cheap and must not block.
+    pub fn fold(
+        cooldown: Duration,
+        mode: DebounceMode,
+        init: A,
+        f: impl Fn(&mut A, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self::from_parts(cooldown, mode, move || init.clone(), f)
+    }
+}
+
+impl<T, A> ValueDebouncer<T, A> {
+    fn from_parts(
+        cooldown: Duration,
+        mode: DebounceMode,
+        empty: impl Fn() -> A + Send + Sync + 'static,
+        merge: impl Fn(&mut A, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self::from_merge(cooldown, mode, empty, move |acc, value| {
+            merge(acc, value);
+            false
+        })
+    }
+
+    fn from_merge(
+        cooldown: Duration,
+        mode: DebounceMode,
+        empty: impl Fn() -> A + Send + Sync + 'static,
+        merge: impl Fn(&mut A, T) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            debouncer: Debouncer::new(cooldown, mode),
+            acc: Arc::new(Accumulator {
+                slot: Mutex::new(empty()),
+                empty: Box::new(empty),
+                merge: Box::new(merge),
+            }),
+        }
+    }
+
+    /// The underlying signal debouncer.
+    pub fn debouncer(&self) -> &Debouncer {
+        &self.debouncer
+    }
+
+    /// Merge `value` into the pending payload and trigger the debouncer.
+    ///
+    /// Once the debouncer is closed the value is dropped instead of merged, as no batch
+    /// would ever hand it out.
+    pub fn trigger_with(&self, value: T) {
+        let fire_now = {
+            // Trigger and merge under the slot lock, so a worker taking the payload sees
+            // either both or neither
+            let mut slot = self.acc.slot.risky_lock();
+            if self.debouncer.try_trigger().is_err() {
+                return;
+            }
+            (self.acc.merge)(&mut slot, value)
+        };
+        if fire_now {
+            self.debouncer.flush();
+        }
+    }
+
+    /// Wait until the debouncer is ready to run.
+    /// Returns a guard holding the accumulated payload; the debounce is finalized when it is dropped.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe, see [`Debouncer::ready`].
+    pub async fn ready<'a>(&self) -> ValueGuard<'a, T, A> {
+        let guard = self.debouncer.ready().await;
+        ValueGuard {
+            guard,
+            acc: self.acc.clone(),
+        }
+    }
+
+    /// Wait until ready and take the accumulated payload in one step.
+    ///
+    /// The payload is moved out as soon as the batch is claimed, so it can't be taken twice
+    /// or forgotten. The returned guard commits the batch when dropped, as usual.
+    ///
+    /// # Cancel Safety
+    /// This method is cancel-safe: nothing is taken until readiness resolves.
+    pub async fn next_value<'a>(&self) -> (DebouncerGuard<'a>, A) {
+        let mut guard = self.ready().await;
+        let value = guard.take();
+        (guard.guard, value)
+    }
+
+    /// Spawn a task that sends each batch's payload into `tx`, see [`Debouncer::pipe_to`].
+    pub fn pipe_to(&self, tx: mpsc::Sender<A>) -> JoinHandle<()>
+    where
+        T: 'static,
+        A: Send + 'static,
+    {
+        let debouncer = self.debouncer.clone();
+        let acc = self.acc.clone();
+        tokio::spawn(async move {
+            loop {
+                let batch = tokio::select! {
+                    _ = tx.closed() => return,
+                    batch = debouncer.wait_ready() => match batch {
+                        Some(batch) => batch,
+                        None => return,
+                    },
+                };
+                let mut guard = debouncer.owned_guard(batch);
+                let value = acc.take(|| guard.absorb_late());
+                if tx.send(value).await.is_err() {
+                    return;
+                }
+            }
+        })
+    }
+
+    /// Wrap a guard of the underlying debouncer so the batch's payload can be taken.
+    #[cfg(feature = "stream")]
+    pub(crate) fn value_guard<'a>(&self, guard: DebouncerGuard<'a>) -> ValueGuard<'a, T, A> {
+        ValueGuard {
+            guard,
+            acc: self.acc.clone(),
+        }
+    }
+
+    /// Take the pending batch right now, ignoring the cooldown, see [`Debouncer::drain_ready`].
+    pub fn drain_ready<'a>(&self) -> Option<ValueGuard<'a, T, A>> {
+        let guard = self.debouncer.drain_ready()?;
+        Some(ValueGuard {
+            guard,
+            acc: self.acc.clone(),
+        })
+    }
+}
+
+/// Guard returned by ValueDebouncer::ready().
+///
+/// Finalizes the debounce state when dropped, like [`DebouncerGuard`].
+pub struct ValueGuard<'a, T, A = Option<T>> {
+    guard: DebouncerGuard<'a>,
+    acc: Arc<Accumulator<T, A>>,
+}
+
+impl<'a, T, A> ValueGuard<'a, T, A> {
+    /// Take the accumulated payload, leaving it empty so the next batch needs a fresh trigger.
+    ///
+    /// The payload is swapped out for a fresh empty one, never cloned. Values that arrived
+    /// while this guard was held are taken along and count toward this batch, so they don't
+    /// fire again as an empty batch.
+    pub fn take(&mut self) -> A {
+        let guard = &mut self.guard;
+        self.acc.take(|| guard.absorb_late())
+    }
+
+    /// Number of triggers coalesced into this batch.
+    pub fn coalesced_count(&self) -> u64 {
+        self.guard.coalesced_count()
+    }
+}
+
+impl<'a, T> ValueGuard<'a, T, VecDeque<T>> {
+    /// Take every queued value, oldest first, leaving the queue empty.
+    pub fn drain(&mut self) -> vec_deque::IntoIter<T> {
+        self.take().into_iter()
+    }
+}
+
+impl<'a, T> ValueGuard<'a, T, HashSet<T>> {
+    /// Take every distinct key of the batch, in arbitrary order, leaving the set empty.
+    pub fn drain(&mut self) -> hash_set::IntoIter<T> {
+        self.take().into_iter()
+    }
+}
@@ -0,0 +1,28 @@
+// tests/async_std.rs
+#![cfg(feature = "async-std")]
+
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_debouncer::{AsyncStdClock, DebounceMode, Debouncer};
+
+#[async_std::test]
+async fn trailing_timing_under_async_std() {
+    // Test: With AsyncStdClock the trailing cooldown is waited out on async-std, without a Tokio runtime
+    let debounce = Debouncer::with_clock(Duration::from_millis(100), DebounceMode::Trailing, AsyncStdClock);
+    let trigger = debounce.clone();
+    let start = Instant::now();
+    async_std::task::spawn(async move {
+        trigger.trigger();
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        trigger.trigger();
+    });
+
+    let guard = debounce.ready().await;
+    let elapsed = start.elapsed();
+    assert_eq!(guard.coalesced_count(), 2);
+    assert!(elapsed >= Duration::from_millis(150), "fired after {:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(2), "fired after {:?}", elapsed);
+
+    drop(guard);
+    assert!(debounce.ready_timeout(Duration::from_millis(50)).await.is_none());
+}
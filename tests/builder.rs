@@ -0,0 +1,353 @@
+// tests/builder.rs
+
+use tokio_debouncer::{BuildError, DebounceMode, Debouncer, DebouncerBuilder, DebouncerConfig};
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn fully_specified_builder() {
+    // Test: Every builder option is applied to the built debouncer
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Trailing)
+        .max_wait(Duration::from_secs(3))
+        .build()
+        .unwrap();
+    assert_eq!(debounce.cooldown(), Duration::from_secs(1));
+    assert_eq!(debounce.mode(), DebounceMode::Trailing);
+
+    // The max-wait cap wins over a trigger stream that keeps extending the cooldown
+    let start = time::Instant::now();
+    let trigger = debounce.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(500)).await;
+        }
+    });
+    let _guard = debounce.ready().await;
+    handle.abort();
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+}
+
+#[test]
+fn max_wait_below_cooldown_is_rejected() {
+    // Test: build() refuses a max-wait shorter than the cooldown
+    let result = DebouncerBuilder::new()
+        .cooldown(Duration::from_secs(5))
+        .max_wait(Duration::from_secs(1))
+        .build();
+    let err = result.expect_err("build must fail");
+    assert_eq!(
+        err,
+        BuildError::MaxWaitBelowCooldown {
+            cooldown: Duration::from_secs(5),
+            max_wait: Duration::from_secs(1),
+        }
+    );
+    assert!(err.to_string().contains("max_wait"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn jitter_delays_fire_within_window() {
+    // Test: A seeded jitter delays the trailing fire by a reproducible offset within [0, jitter)
+    let fire_time = || async {
+        let debounce = Debouncer::builder()
+            .cooldown(Duration::from_secs(1))
+            .jitter(Duration::from_millis(500))
+            .jitter_seed(42)
+            .build()
+            .unwrap();
+        let start = time::Instant::now();
+        debounce.trigger();
+        let _guard = debounce.ready().await;
+        start.elapsed()
+    };
+
+    let first = fire_time().await;
+    assert!(first >= Duration::from_secs(1), "jitter must never advance a fire: {:?}", first);
+    assert!(first < Duration::from_millis(1500), "jitter exceeded its window: {:?}", first);
+    assert_eq!(fire_time().await, first, "the same seed reproduces the same offset");
+}
+
+#[tokio::test(start_paused = true)]
+async fn max_batch_fires_early() {
+    // Test: Reaching max_batch makes ready() resolve immediately, ignoring the cooldown
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(10))
+        .max_batch(3)
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.trigger();
+    assert!(debounce.try_ready().is_none());
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 3);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    drop(guard);
+
+    // A waiter already sleeping on the cooldown is woken by the filling trigger
+    let trigger = debounce.clone();
+    tokio::spawn(async move {
+        for _ in 0..3 {
+            trigger.trigger();
+            time::sleep(Duration::from_secs(1)).await;
+        }
+    });
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 3);
+    assert_eq!(start.elapsed(), Duration::from_secs(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn backoff_grows_then_resets() {
+    // Test: Consecutive fires double the cooldown up to the cap; a quiet period resets it
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .backoff(2, Duration::from_secs(4))
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    let mut fired_at = Vec::new();
+    let mut cooldowns = Vec::new();
+    for _ in 0..5 {
+        // Re-trigger straight after every fire
+        debounce.trigger();
+        drop(debounce.ready().await);
+        fired_at.push(start.elapsed().as_secs());
+        cooldowns.push(debounce.effective_cooldown().as_secs());
+    }
+    assert_eq!(fired_at, vec![0, 1, 3, 7, 11]);
+    assert_eq!(cooldowns, vec![1, 2, 4, 4, 4]);
+    assert_eq!(debounce.cooldown(), Duration::from_secs(1));
+
+    // After a quiet period the next batch fires on the base cooldown again
+    time::sleep(Duration::from_secs(10)).await;
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(21));
+    assert_eq!(debounce.effective_cooldown(), Duration::from_secs(1));
+}
+
+#[test]
+fn const_config() {
+    // Test: DebouncerConfig can be built in a const context
+    const CONFIG: DebouncerConfig = DebouncerConfig::new(500, DebounceMode::Both).max_wait_ms(2000);
+    assert_eq!(CONFIG.cooldown_ms, 500);
+    assert_eq!(CONFIG.max_wait_ms, Some(2000));
+
+    let debounce = Debouncer::from_config(CONFIG).unwrap();
+    assert_eq!(debounce.cooldown(), Duration::from_millis(500));
+    assert_eq!(debounce.mode(), DebounceMode::Both);
+}
+
+#[tokio::test(start_paused = true)]
+async fn strict_leading_discards_triggers_during_cooldown() {
+    // Test: With strict leading a burst fires exactly once, with no catch-up after the cooldown
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .strict_leading(true)
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    time::advance(Duration::from_millis(200)).await;
+    debounce.trigger();
+    time::advance(Duration::from_millis(300)).await;
+    debounce.trigger();
+    assert!(!debounce.is_triggered(), "triggers during the cooldown are discarded");
+
+    let mut yielded = false;
+    tokio::select! {
+        _ = debounce.ready() => { yielded = true; }
+        _ = time::sleep(Duration::from_secs(5)) => {}
+    }
+    assert!(!yielded, "no trailing catch-up fire");
+    assert_eq!(debounce.stats().fires, 1);
+
+    // After the cooldown the next trigger fires immediately again
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(debounce.stats().fires, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn strict_leading_discards_triggers_during_processing() {
+    // Test: Triggers arriving while the leading guard is held do not cause a second fire
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .strict_leading(true)
+        .build()
+        .unwrap();
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    debounce.trigger();
+    debounce.trigger();
+    assert_eq!(guard.coalesced_count(), 1);
+    drop(guard);
+    assert!(!debounce.is_triggered(), "triggers during processing are discarded");
+
+    assert!(debounce.ready_timeout(Duration::from_secs(5)).await.is_none());
+    assert_eq!(debounce.stats().fires, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn high_priority_trigger_fires_earlier() {
+    // Test: A high-priority trigger shortens the deadline of a burst started at low priority
+    use tokio_debouncer::Priority;
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(5))
+        .priority_cooldown(Priority::High, Duration::from_millis(500))
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+
+    debounce.trigger_with_priority(Priority::Low);
+    time::advance(Duration::from_secs(1)).await;
+    debounce.trigger_with_priority(Priority::High);
+    let guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(1500));
+    assert_eq!(guard.coalesced_count(), 2);
+    drop(guard);
+
+    // The next burst without urgent triggers waits the regular cooldown again
+    let start = time::Instant::now();
+    debounce.trigger_with_priority(Priority::Low);
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn dedup_window_is_separate_from_cooldown() {
+    // Test: Triggers inside the dedup window are dropped, later ones inside the cooldown still fire
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .dedup_window(Duration::from_millis(10))
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    debounce.trigger();
+    drop(guard);
+    time::advance(Duration::from_millis(5)).await;
+    debounce.trigger();
+    assert!(!debounce.is_triggered(), "triggers within the dedup window are discarded");
+
+    time::advance(Duration::from_millis(100)).await;
+    debounce.trigger();
+    assert!(debounce.is_triggered(), "triggers after the dedup window are kept");
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(1), "the cooldown still spaces fires");
+    assert_eq!(debounce.stats().fires, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cooldown_from_trigger_extends_leading_suppression() {
+    // Test: By default the cooldown runs from the fire; with the flag each trigger restarts it
+    async fn second_fire_at(from_trigger: bool) -> Duration {
+        let debounce = Debouncer::builder()
+            .cooldown(Duration::from_secs(1))
+            .mode(DebounceMode::Leading)
+            .cooldown_from_trigger(from_trigger)
+            .build()
+            .unwrap();
+        let start = time::Instant::now();
+        debounce.trigger();
+        drop(debounce.ready().await);
+        assert_eq!(start.elapsed(), Duration::ZERO, "the first trigger fires immediately");
+
+        let trigger = debounce.clone();
+        tokio::spawn(async move {
+            for _ in 0..3 {
+                time::sleep(Duration::from_millis(500)).await;
+                trigger.trigger();
+            }
+        });
+        drop(debounce.ready().await);
+        start.elapsed()
+    }
+
+    assert_eq!(second_fire_at(false).await, Duration::from_secs(1));
+    assert_eq!(second_fire_at(true).await, Duration::from_millis(2500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn gap_after_processing_spaces_batches_from_their_end() {
+    // Test: With slow processing, the flag measures the cooldown from the end of each batch
+    async fn batch_starts(gap: bool) -> Vec<Duration> {
+        let debounce = Debouncer::builder()
+            .cooldown(Duration::from_millis(100))
+            .mode(DebounceMode::Throttle)
+            .gap_after_processing(gap)
+            .build()
+            .unwrap();
+        let trigger = debounce.clone();
+        let source = tokio::spawn(async move {
+            loop {
+                trigger.trigger();
+                time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+        let start = time::Instant::now();
+        let mut starts = Vec::new();
+        for _ in 0..3 {
+            let _guard = debounce.ready().await;
+            starts.push(start.elapsed());
+            // Slow processing while the guard is held
+            time::sleep(Duration::from_millis(80)).await;
+        }
+        source.abort();
+        starts
+    }
+
+    let ms = Duration::from_millis;
+    // By default Throttle keeps its cadence, leaving only 20ms between batches
+    assert_eq!(batch_starts(false).await, vec![ms(0), ms(100), ms(200)]);
+    assert_eq!(batch_starts(true).await, vec![ms(0), ms(180), ms(360)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fire_on_last_drop_runs_callback_for_pending_batch() {
+    // Test: Dropping the last handle with a pending trigger runs on_fire only when enabled
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    for (enabled, expected) in [(false, 0), (true, 1)] {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let debounce = Debouncer::builder()
+            .cooldown(Duration::from_secs(1))
+            .fire_on_last_drop(enabled)
+            .build()
+            .unwrap();
+        let counter = fired.clone();
+        debounce.on_fire(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        debounce.trigger();
+
+        let clone = debounce.clone();
+        drop(debounce);
+        assert_eq!(fired.load(Ordering::SeqCst), 0, "a clone is still alive");
+        drop(clone);
+        assert_eq!(fired.load(Ordering::SeqCst), expected, "enabled: {}", enabled);
+    }
+
+    // Nothing pending, nothing fired
+    let fired = Arc::new(AtomicUsize::new(0));
+    let debounce = Debouncer::builder().fire_on_last_drop(true).build().unwrap();
+    let counter = fired.clone();
+    debounce.on_fire(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    drop(debounce);
+    assert_eq!(fired.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,30 @@
+// tests/cancellation.rs
+#![cfg(feature = "cancellation")]
+
+use tokio_debouncer::{DebounceMode, Debouncer};
+use tokio_util::sync::CancellationToken;
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn cancel_mid_wait_keeps_trigger_pending() {
+    // Test: Cancelling the token mid-wait returns None without committing the batch
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let token = CancellationToken::new();
+    debounce.trigger();
+
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        time::sleep(Duration::from_millis(500)).await;
+        canceller.cancel();
+    });
+    let start = time::Instant::now();
+    assert!(debounce.ready_with_cancel(&token).await.is_none());
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+    assert!(debounce.is_triggered());
+    assert_eq!(debounce.stats().fires, 0);
+
+    // A fresh token sees the still-pending batch
+    let guard = debounce.ready_with_cancel(&CancellationToken::new()).await;
+    assert!(guard.is_some());
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+}
@@ -0,0 +1,171 @@
+// tests/clock.rs
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tokio::sync::Notify;
+use tokio::time::{Duration, Instant};
+use tokio_debouncer::{Clock, DebounceMode, Debouncer, WallClock};
+
+/// Synthetic clock that only moves when advanced manually.
+struct MockClock {
+    now: Mutex<Instant>,
+    advanced: Notify,
+}
+
+impl MockClock {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            now: Mutex::new(Instant::now()),
+            advanced: Notify::new(),
+        })
+    }
+
+    fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+        self.advanced.notify_waiters();
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            loop {
+                let advanced = self.advanced.notified();
+                if self.now() >= deadline {
+                    return;
+                }
+                advanced.await;
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn mock_clock_drives_trailing_fire() {
+    // Test: A manually advanced clock controls fire timing without start_paused
+    let clock = MockClock::new();
+    let debounce = Debouncer::with_clock(Duration::from_secs(5), DebounceMode::Trailing, clock.clone());
+    debounce.trigger();
+
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move {
+        let _guard = waiter.ready().await;
+    });
+
+    clock.advance(Duration::from_secs(4));
+    tokio::task::yield_now().await;
+    assert!(!handle.is_finished(), "Should not fire before the mock cooldown");
+
+    clock.advance(Duration::from_secs(1));
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("fires once the mock clock passes the cooldown")
+        .unwrap();
+    assert!(!debounce.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn wall_clock_forward_jump_fires_early() {
+    // Test: Jumping the wall clock forward shortens the remaining cooldown
+    let start = Instant::now();
+    let jump = Arc::new(AtomicU64::new(0));
+    let source = {
+        let jump = jump.clone();
+        move || {
+            let elapsed = Instant::now() - start + Duration::from_secs(jump.load(Ordering::SeqCst));
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000) + elapsed
+        }
+    };
+    let clock = WallClock::with_source(source).poll_interval(Duration::from_millis(100));
+    let debounce = Debouncer::with_clock(Duration::from_secs(60), DebounceMode::Trailing, clock);
+    debounce.trigger();
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(debounce.try_ready().is_none());
+
+    // NTP-style correction moves wall time 58s ahead; only ~1s of cooldown remains
+    jump.store(58, Ordering::SeqCst);
+    let _guard = debounce.ready().await;
+    let waited = Instant::now() - start;
+    assert!(waited < Duration::from_secs(3), "fired after {waited:?}");
+}
+
+#[test]
+#[should_panic(expected = "requires a Tokio runtime, but none is running")]
+fn tokio_clock_without_runtime_panics_with_hint() {
+    // Test: Sleeping on the Tokio clock outside any runtime panics with the crate's own message
+    let _sleep = tokio_debouncer::TokioClock.sleep_until(Instant::now() + Duration::from_millis(10));
+}
+
+#[test]
+#[should_panic(expected = "enable_time")]
+fn runtime_without_timers_panics_with_hint() {
+    // Test: In a runtime without the timer driver, Tokio's own panic names the fix, as documented
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(async {
+        let debounce = Debouncer::with_clock(Duration::from_millis(10), DebounceMode::Trailing, tokio_debouncer::TokioClock);
+        debounce.trigger();
+        drop(debounce.ready().await);
+    });
+}
+
+/// Tokio clock that counts the sleeps currently in flight.
+struct CountingClock {
+    sleeping: Arc<AtomicU64>,
+}
+
+struct SleepToken(Arc<AtomicU64>);
+
+impl Drop for SleepToken {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Clock for CountingClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.sleeping.fetch_add(1, Ordering::SeqCst);
+        let token = SleepToken(self.sleeping.clone());
+        Box::pin(async move {
+            tokio::time::sleep_until(deadline).await;
+            drop(token);
+        })
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn reset_wakes_sleeping_waiter() {
+    // Test: reset() makes a waiter sleeping on the cooldown re-park right away instead of at the old deadline
+    let sleeping = Arc::new(AtomicU64::new(0));
+    let clock = CountingClock { sleeping: sleeping.clone() };
+    let debounce = Debouncer::with_clock(Duration::from_secs(10), DebounceMode::Trailing, clock);
+    debounce.trigger();
+
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move {
+        let _guard = waiter.ready().await;
+    });
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert_eq!(sleeping.load(Ordering::SeqCst), 1, "waiter sleeps on the cooldown");
+
+    debounce.reset();
+    tokio::task::yield_now().await;
+    assert!(!debounce.is_triggered());
+    assert_eq!(sleeping.load(Ordering::SeqCst), 0, "waiter dropped its stale sleep");
+    assert!(!handle.is_finished());
+
+    tokio::time::sleep(Duration::from_secs(20)).await;
+    assert!(!handle.is_finished(), "nothing fires after a reset");
+    handle.abort();
+}
@@ -0,0 +1,89 @@
+// tests/config.rs
+#![cfg(feature = "serde")]
+
+use tokio_debouncer::{BuildError, DebounceMode, Debouncer, DebouncerConfig};
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn config_round_trips_through_json() {
+    // Test: A serialized config rebuilds an equivalent debouncer
+    let config = DebouncerConfig {
+        cooldown_ms: 1000,
+        mode: DebounceMode::Trailing,
+        max_wait_ms: Some(3000),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"cooldown_ms":1000,"mode":"trailing","max_wait_ms":3000}"#);
+    let parsed: DebouncerConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, config);
+
+    let debounce = Debouncer::from_config(parsed).unwrap();
+    assert_eq!(debounce.cooldown(), Duration::from_secs(1));
+    assert_eq!(debounce.mode(), DebounceMode::Trailing);
+
+    // The max-wait cap survives the round trip
+    let start = time::Instant::now();
+    let trigger = debounce.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(500)).await;
+        }
+    });
+    let _guard = debounce.ready().await;
+    handle.abort();
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+}
+
+#[test]
+fn config_without_max_wait_and_invalid_config() {
+    // Test: max_wait_ms may be omitted, and an invalid config is rejected
+    let config: DebouncerConfig = serde_json::from_str(r#"{"cooldown_ms":250,"mode":"both"}"#).unwrap();
+    assert_eq!(config.mode, DebounceMode::Both);
+    assert_eq!(config.max_wait_ms, None);
+
+    let invalid = DebouncerConfig { max_wait_ms: Some(100), ..config };
+    assert_eq!(
+        Debouncer::from_config(invalid).unwrap_err(),
+        BuildError::MaxWaitBelowCooldown {
+            cooldown: Duration::from_millis(250),
+            max_wait: Duration::from_millis(100),
+        }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn snapshot_restores_remaining_cooldown() {
+    // Test: A snapshot taken mid-cooldown resumes with about the same time left
+    use tokio_debouncer::DebouncerSnapshot;
+    let config = DebouncerConfig::new(1000, DebounceMode::Trailing);
+    let debounce = Debouncer::from_config(config).unwrap();
+    debounce.trigger();
+    time::advance(Duration::from_millis(400)).await;
+
+    let snapshot = debounce.snapshot();
+    assert_eq!(snapshot, DebouncerSnapshot { has_run: false, triggered: true, since_last_run_ms: 400 });
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let parsed: DebouncerSnapshot = serde_json::from_str(&json).unwrap();
+    drop(debounce);
+
+    let restored = Debouncer::restore(config, parsed).unwrap();
+    assert!(restored.is_triggered());
+    assert_eq!(restored.time_until_ready(), Some(Duration::from_millis(600)));
+    let start = time::Instant::now();
+    drop(restored.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_millis(600));
+}
+
+#[test]
+fn rate_limited_mode_uses_snake_case_and_millis() {
+    // Test: RateLimited serializes as "rate_limited" with its window in milliseconds, like the other _ms fields
+    let config = DebouncerConfig::new(0, DebounceMode::RateLimited { max: 5, window: Duration::from_secs(1) });
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"cooldown_ms":0,"mode":{"rate_limited":{"max":5,"window_ms":1000}},"max_wait_ms":null}"#
+    );
+    let parsed: DebouncerConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, config);
+}
@@ -2,6 +2,7 @@
 
 use tokio_debouncer::Debouncer;
 use tokio_debouncer::DebounceMode;
+use tokio_debouncer::DebouncerStatus;
 use tokio::time::{self, Duration};
 
 
@@ -12,7 +13,7 @@ async fn leading_runs_immediately_on_first_trigger() {
     debounce.trigger();
 
     let _guard = debounce.ready().await;
-    assert!(debounce.is_triggered().await); // should still be triggered until guard is dropped
+    assert!(debounce.is_triggered()); // should still be triggered until guard is dropped
 }
 
 #[tokio::test(start_paused = true)]
@@ -83,7 +84,7 @@ async fn done_clears_trigger_flag() {
         let _guard = debounce.ready().await;
         // guard dropped here
     }
-    assert!(!debounce.is_triggered().await);
+    assert!(!debounce.is_triggered());
 }
 
 
@@ -108,3 +109,1629 @@ async fn multiple_triggers_yield_only_once() {
 }
 
 
+#[tokio::test(start_paused = true)]
+async fn both_single_trigger_fires_only_leading() {
+    // Test: Both mode fires immediately and skips the trailing fire when no further triggers arrive
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Both);
+
+    debounce.trigger();
+    debounce.ready().await; // leading fire, no time advance needed
+
+    let mut yielded = false;
+    tokio::select! {
+        _ = debounce.ready() => { yielded = true; }
+        _ = time::sleep(Duration::from_secs(10)) => {}
+    }
+    assert!(!yielded, "No trailing fire without additional triggers");
+}
+
+#[tokio::test(start_paused = true)]
+async fn both_burst_fires_leading_and_trailing() {
+    // Test: Both mode fires on the leading edge and again after the cooldown for a burst
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Both);
+    let start = time::Instant::now();
+
+    debounce.trigger();
+    debounce.trigger();
+    debounce.trigger();
+    debounce.ready().await; // leading fire
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    let mut yielded = false;
+    tokio::select! {
+        _ = debounce.ready() => { yielded = true; }
+        _ = time::sleep(Duration::from_secs(4)) => {}
+    }
+    assert!(!yielded, "Trailing fire must wait for the cooldown");
+
+    debounce.ready().await; // trailing fire
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn max_wait_caps_trailing_starvation() {
+    // Test: A relentless trigger stream cannot delay a trailing fire past max_wait
+    let debounce = Debouncer::with_max_wait(
+        Duration::from_secs(1),
+        DebounceMode::Trailing,
+        Duration::from_secs(3),
+    );
+    let start = time::Instant::now();
+
+    let trigger = debounce.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(50)).await;
+        }
+    });
+
+    let _guard = debounce.ready().await;
+    let elapsed = start.elapsed();
+    handle.abort();
+    assert!(elapsed >= Duration::from_secs(3), "fired too early: {:?}", elapsed);
+    assert!(elapsed < Duration::from_millis(3050), "fired too late: {:?}", elapsed);
+}
+
+#[tokio::test]
+async fn with_max_wait_raises_cap_to_cooldown() {
+    // Test: A max_wait below the cooldown is clamped up instead of producing an invalid config
+    let debounce = Debouncer::with_max_wait(Duration::from_secs(2), DebounceMode::Trailing, Duration::from_secs(1));
+    assert_eq!(debounce.config().max_wait, Some(Duration::from_secs(2)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn guard_reports_coalesced_count() {
+    // Test: The guard reports every trigger coalesced into the batch, and the count resets afterwards
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+
+    for _ in 0..5 {
+        debounce.trigger();
+        time::advance(Duration::from_secs(1)).await;
+    }
+    {
+        let guard = debounce.ready().await;
+        assert_eq!(guard.coalesced_count(), 5);
+    }
+
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_ready_respects_trailing_cooldown() {
+    // Test: try_ready returns None before the trailing cooldown and Some after
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+    assert!(debounce.try_ready().is_none(), "Not triggered yet");
+
+    debounce.trigger();
+    time::advance(Duration::from_secs(4)).await;
+    assert!(debounce.try_ready().is_none(), "Cooldown not elapsed");
+
+    time::advance(Duration::from_secs(1)).await;
+    assert!(debounce.try_ready().is_some());
+    assert!(!debounce.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_ready_respects_leading_cooldown() {
+    // Test: try_ready fires immediately in leading mode, then only after the cooldown
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Leading);
+
+    debounce.trigger();
+    assert!(debounce.try_ready().is_some());
+
+    debounce.trigger();
+    time::advance(Duration::from_secs(4)).await;
+    assert!(debounce.try_ready().is_none(), "Cooldown not elapsed");
+
+    time::advance(Duration::from_secs(1)).await;
+    assert!(debounce.try_ready().is_some());
+}
+
+#[tokio::test]
+async fn accessors_return_configuration() {
+    // Test: cooldown() and mode() read back the constructor arguments
+    let debounce = Debouncer::new(Duration::from_millis(250), DebounceMode::Leading);
+    assert_eq!(debounce.cooldown(), Duration::from_millis(250));
+    assert_eq!(debounce.mode(), DebounceMode::Leading);
+
+    let debounce = Debouncer::new(Duration::from_secs(3), DebounceMode::Trailing);
+    assert_eq!(debounce.cooldown(), Duration::from_secs(3));
+    assert_eq!(debounce.mode(), DebounceMode::Trailing);
+}
+
+#[tokio::test(start_paused = true)]
+async fn set_cooldown_shortens_pending_wait() {
+    // Test: Shortening the cooldown while ready() is waiting brings the fire forward
+    let debounce = Debouncer::new(Duration::from_secs(10), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+
+    let adjuster = debounce.clone();
+    tokio::spawn(async move {
+        time::sleep(Duration::from_secs(2)).await;
+        adjuster.set_cooldown(Duration::from_secs(3));
+    });
+
+    let _guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+    assert_eq!(debounce.cooldown(), Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn reset_discards_pending_trigger() {
+    // Test: A concurrent ready() does not resolve after trigger(); reset();
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move {
+        let _guard = waiter.ready().await;
+    });
+
+    debounce.trigger();
+    time::advance(Duration::from_secs(1)).await;
+    debounce.reset();
+    assert!(!debounce.is_triggered());
+
+    time::sleep(Duration::from_secs(20)).await;
+    assert!(!handle.is_finished(), "ready() must not resolve after reset");
+
+    debounce.trigger();
+    time::sleep(Duration::from_secs(5)).await;
+    tokio::task::yield_now().await;
+    assert!(handle.is_finished(), "a fresh trigger fires again");
+}
+
+#[tokio::test(start_paused = true)]
+async fn reset_with_rearm_restores_leading_edge() {
+    // Test: reset() keeps the leading cooldown, reset_with(true) re-arms the immediate fire
+    let debounce = Debouncer::new(Duration::from_secs(10), DebounceMode::Leading);
+    debounce.trigger();
+    debounce.ready().await;
+
+    debounce.trigger();
+    debounce.reset();
+    debounce.trigger();
+    assert!(debounce.try_ready().is_none(), "reset keeps the cooldown");
+
+    debounce.reset_with(true);
+    debounce.trigger();
+    assert!(debounce.try_ready().is_some(), "re-armed leading edge fires immediately");
+}
+
+#[tokio::test(start_paused = true)]
+async fn flush_fires_without_waiting_for_cooldown() {
+    // Test: trigger(); flush(); resolves ready() without advancing time, and the force is cleared afterwards
+    let debounce = Debouncer::new(Duration::from_secs(60), DebounceMode::Trailing);
+    let start = time::Instant::now();
+
+    debounce.trigger();
+    debounce.flush();
+    debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    debounce.trigger();
+    assert!(debounce.try_ready().is_none(), "flush must not outlive its batch");
+}
+
+#[tokio::test(start_paused = true)]
+async fn flush_without_trigger_is_noop() {
+    // Test: flush() does not create a batch on its own
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.flush();
+    assert!(!debounce.is_triggered());
+    assert!(debounce.try_ready().is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn owned_guard_finalizes_in_spawned_task() {
+    // Test: An owned guard can be moved into another task and still finalizes on drop
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Leading);
+    debounce.trigger();
+    debounce.trigger();
+
+    let guard = debounce.ready_owned().await;
+    assert_eq!(guard.coalesced_count(), 2);
+    assert!(debounce.is_triggered());
+
+    tokio::spawn(async move {
+        let _guard = guard;
+        time::sleep(Duration::from_secs(1)).await;
+    })
+    .await
+    .unwrap();
+    assert!(!debounce.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn ready_timeout_elapses_before_cooldown() {
+    // Test: ready_timeout returns None before the cooldown and keeps the trigger pending
+    let debounce = Debouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+    debounce.trigger();
+
+    assert!(debounce.ready_timeout(Duration::from_secs(2)).await.is_none());
+    assert!(debounce.is_triggered(), "Timeout must not commit the batch");
+
+    assert!(debounce.ready_timeout(Duration::from_secs(10)).await.is_some());
+}
+
+#[tokio::test(start_paused = true)]
+async fn throttle_fires_on_fixed_cadence() {
+    // Test: A continuous trigger stream yields at a regular cooldown cadence, unaffected by processing time
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Throttle);
+    let start = time::Instant::now();
+
+    let trigger = debounce.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let mut fires = Vec::new();
+    for _ in 0..4 {
+        let _guard = debounce.ready().await;
+        fires.push(start.elapsed());
+        time::sleep(Duration::from_millis(300)).await; // simulated processing
+    }
+    handle.abort();
+    assert_eq!(
+        fires,
+        vec![
+            Duration::ZERO,
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(3),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn throttle_flush_keeps_cadence_from_actual_fire() {
+    // Test: A flushed Throttle batch records its real fire time, in last_fired and interval_stats,
+    // and the next cadence slot counts from it
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Throttle);
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.ready().await;
+
+    time::advance(Duration::from_millis(10)).await;
+    debounce.trigger();
+    debounce.flush();
+    debounce.ready().await;
+    assert_eq!(debounce.last_fired(), Some(start + Duration::from_millis(10)));
+    assert_eq!(debounce.interval_stats().max, Duration::from_millis(10));
+
+    debounce.trigger();
+    debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(1010));
+    assert_eq!(debounce.interval_stats().max, Duration::from_secs(1));
+}
+
+#[test]
+fn modes_compare_by_value() {
+    // Test: DebounceMode is Copy and comparable
+    let mode = DebounceMode::Leading;
+    let copy = mode;
+    assert_eq!(mode, copy);
+    assert_ne!(DebounceMode::Leading, DebounceMode::Trailing);
+    assert_ne!(DebounceMode::Both, DebounceMode::Throttle);
+}
+
+#[tokio::test]
+async fn default_is_trailing_with_documented_cooldown() {
+    // Test: Debouncer::default() is a trailing debouncer with a 100ms cooldown
+    let debounce = Debouncer::default();
+    assert_eq!(debounce.mode(), DebounceMode::Trailing);
+    assert_eq!(DebounceMode::default(), DebounceMode::Trailing);
+    assert_eq!(debounce.cooldown(), Duration::from_millis(100));
+    assert_eq!(debounce.cooldown(), Debouncer::DEFAULT_COOLDOWN);
+}
+
+#[tokio::test(start_paused = true)]
+async fn last_fired_advances_on_each_fire() {
+    // Test: last_fired() is None until the first fire and advances with every batch
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    assert_eq!(debounce.last_fired(), None);
+
+    debounce.trigger();
+    debounce.ready().await;
+    let first = debounce.last_fired().expect("fired once");
+
+    time::advance(Duration::from_secs(3)).await;
+    debounce.trigger();
+    debounce.ready().await;
+    let second = debounce.last_fired().expect("fired twice");
+    assert_eq!(second - first, Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_flush_records_actual_fire_time() {
+    // Test: A Leading batch flushed ahead of its slot counts from when it fired, not the slot
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.ready().await;
+
+    time::advance(Duration::from_millis(10)).await;
+    debounce.trigger();
+    debounce.flush();
+    debounce.ready().await;
+    assert_eq!(debounce.last_fired(), Some(start + Duration::from_millis(10)));
+
+    // After a quiet period the next trigger fires right away
+    time::advance(Duration::from_millis(1200)).await;
+    debounce.trigger();
+    let quiet = time::Instant::now();
+    debounce.ready().await;
+    assert_eq!(quiet.elapsed(), Duration::ZERO);
+    assert_eq!(debounce.last_fired(), Some(quiet));
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_max_batch_records_actual_fire_time() {
+    // Test: A Leading batch fired early by max_batch never records a fire time in the future
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(10))
+        .mode(DebounceMode::Leading)
+        .max_batch(2)
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.ready().await;
+
+    time::advance(Duration::from_millis(5)).await;
+    debounce.trigger();
+    debounce.trigger();
+    debounce.ready().await;
+    assert_eq!(debounce.last_fired(), Some(start + Duration::from_millis(5)));
+
+    // The next window counts from the early fire
+    time::advance(Duration::from_secs(11)).await;
+    debounce.trigger();
+    let quiet = time::Instant::now();
+    debounce.ready().await;
+    assert_eq!(quiet.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_max_wait_records_actual_fire_time() {
+    // Test: A Leading batch forced out by max_wait records when it fired, not the restarted cooldown
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .cooldown_from_trigger(true)
+        .max_wait(Duration::from_secs(2))
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.ready().await;
+
+    // Triggers every 400ms keep restarting the cooldown; max_wait fires 2s after the first
+    let trigger = debounce.clone();
+    let source = tokio::spawn(async move {
+        loop {
+            time::sleep(Duration::from_millis(400)).await;
+            trigger.trigger();
+        }
+    });
+    debounce.ready().await;
+    let fired = time::Instant::now();
+    source.abort();
+    assert_eq!(fired - start, Duration::from_millis(2400));
+    assert_eq!(debounce.last_fired(), Some(fired));
+}
+
+#[tokio::test(start_paused = true)]
+async fn weak_handle_does_not_keep_debouncer_alive() {
+    // Test: A weak handle triggers while the debouncer lives and becomes inert once it is dropped
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let weak = debounce.downgrade();
+
+    weak.trigger();
+    assert!(debounce.is_triggered());
+    assert!(weak.upgrade().is_some());
+
+    let clone = debounce.clone();
+    drop(debounce);
+    assert!(weak.upgrade().is_some(), "a clone still holds the debouncer");
+    drop(clone);
+
+    assert!(weak.upgrade().is_none());
+    weak.trigger(); // no-op, must not panic
+}
+
+#[tokio::test(start_paused = true)]
+async fn stats_count_triggers_and_fires() {
+    // Test: stats() reports every trigger and every committed batch
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+
+    for _ in 0..2 {
+        for _ in 0..3 {
+            debounce.trigger();
+        }
+        debounce.ready().await;
+    }
+
+    let stats = debounce.stats();
+    assert_eq!(stats.triggers, 6);
+    assert_eq!(stats.fires, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn stats_skip_ignored_triggers() {
+    // Test: Triggers after close() and triggers discarded by strict leading are not counted
+    use tokio_debouncer::DebouncerStats;
+    let debounce = Debouncer::builder()
+        .cooldown(Duration::from_secs(1))
+        .mode(DebounceMode::Leading)
+        .strict_leading(true)
+        .build()
+        .unwrap();
+    debounce.trigger();
+    debounce.ready().await;
+    debounce.trigger();
+    assert_eq!(debounce.stats().triggers, 1);
+
+    debounce.close();
+    debounce.trigger();
+    assert!(debounce.try_trigger().is_err());
+    assert_eq!(debounce.stats(), DebouncerStats { triggers: 1, fires: 1 });
+}
+
+#[tokio::test(start_paused = true)]
+async fn close_unblocks_waiters_without_guard() {
+    // Test: close() wakes a task parked in ready_or_closed() with None and ignores later triggers
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move { waiter.ready_or_closed().await.is_some() });
+
+    time::sleep(Duration::from_secs(5)).await;
+    debounce.close();
+    assert!(!handle.await.unwrap(), "closed waiter must not receive a guard");
+
+    debounce.trigger();
+    assert!(!debounce.is_triggered(), "triggers after close are ignored");
+    assert!(debounce.ready_or_closed().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn close_delivers_pending_batch_first() {
+    // Test: A batch pending at close time is still delivered before None
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger();
+    debounce.close();
+
+    assert!(debounce.ready_or_closed().await.is_some());
+    assert!(debounce.ready_or_closed().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn concurrent_waiters_share_batches_exactly_once() {
+    // Test: With three waiters on clones, each batch is delivered to exactly one of them
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let delivered = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    for _ in 0..3 {
+        let waiter = debounce.clone();
+        let delivered = delivered.clone();
+        tokio::spawn(async move {
+            loop {
+                let _guard = waiter.ready_owned().await;
+                delivered.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                time::sleep(Duration::from_millis(100)).await; // hold the guard while processing
+            }
+        });
+    }
+
+    for _ in 0..5 {
+        debounce.trigger();
+        time::sleep(Duration::from_secs(3)).await;
+    }
+    assert_eq!(delivered.load(std::sync::atomic::Ordering::SeqCst), 5);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stress_concurrent_ready_yields_one_guard_per_batch() {
+    // Test: Many concurrent ready()/try_ready() callers never receive two guards for one batch
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const BATCHES: usize = 200;
+    let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Leading);
+    let guards = Arc::new(AtomicUsize::new(0));
+    let busy = Arc::new(AtomicBool::new(false));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let record = {
+        let guards = guards.clone();
+        let busy = busy.clone();
+        move || {
+            assert!(!busy.swap(true, Ordering::SeqCst), "two guards held for one batch");
+            guards.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_micros(50)); // widen the overlap window
+            busy.store(false, Ordering::SeqCst);
+        }
+    };
+
+    let mut tasks = Vec::new();
+    for _ in 0..12 {
+        let waiter = debounce.clone();
+        let record = record.clone();
+        tasks.push(tokio::spawn(async move {
+            loop {
+                let _guard = waiter.ready_owned().await;
+                record();
+            }
+        }));
+    }
+    let mut threads = Vec::new();
+    for _ in 0..4 {
+        let waiter = debounce.clone();
+        let record = record.clone();
+        let stop = stop.clone();
+        threads.push(std::thread::spawn(move || {
+            while !stop.load(Ordering::SeqCst) {
+                match waiter.try_ready() {
+                    Some(_guard) => record(),
+                    None => std::thread::yield_now(),
+                }
+            }
+        }));
+    }
+
+    time::timeout(Duration::from_secs(10), async {
+        for batch in 1..=BATCHES {
+            debounce.trigger();
+            while debounce.stats().fires < batch as u64 {
+                tokio::task::yield_now().await;
+            }
+        }
+    })
+    .await
+    .expect("every batch is delivered");
+    time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(guards.load(Ordering::SeqCst), BATCHES);
+
+    stop.store(true, Ordering::SeqCst);
+    for thread in threads {
+        thread.join().expect("a try_ready caller panicked");
+    }
+    for task in tasks {
+        assert!(!task.is_finished(), "a ready() caller panicked");
+        task.abort();
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn time_until_ready_counts_down() {
+    // Test: time_until_ready() reports the remaining cooldown as time advances
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    assert_eq!(debounce.time_until_ready(), None);
+
+    debounce.trigger();
+    assert_eq!(debounce.time_until_ready(), Some(Duration::from_secs(1)));
+    time::advance(Duration::from_millis(300)).await;
+    assert_eq!(debounce.time_until_ready(), Some(Duration::from_millis(700)));
+    time::advance(Duration::from_millis(700)).await;
+    assert_eq!(debounce.time_until_ready(), Some(Duration::ZERO));
+
+    let _guard = debounce.ready().await;
+    assert_eq!(debounce.time_until_ready(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn status_transitions() {
+    // Test: status() walks through Idle, Pending, Ready, Idle (guard held) and Closed
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    assert_eq!(debounce.status(), DebouncerStatus::Idle);
+
+    debounce.trigger();
+    assert_eq!(
+        debounce.status(),
+        DebouncerStatus::Pending { fires_in: Duration::from_secs(1) }
+    );
+    time::advance(Duration::from_millis(400)).await;
+    assert_eq!(
+        debounce.status(),
+        DebouncerStatus::Pending { fires_in: Duration::from_millis(600) }
+    );
+    time::advance(Duration::from_millis(600)).await;
+    assert_eq!(debounce.status(), DebouncerStatus::Ready);
+
+    let guard = debounce.ready().await;
+    assert_eq!(debounce.status(), DebouncerStatus::Idle);
+    drop(guard);
+    assert_eq!(debounce.status(), DebouncerStatus::Idle);
+
+    // A batch pending at close time is still reported until it is delivered
+    debounce.trigger();
+    debounce.close();
+    assert!(matches!(debounce.status(), DebouncerStatus::Pending { .. }));
+    drop(debounce.ready().await);
+    assert_eq!(debounce.status(), DebouncerStatus::Closed);
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_and_wait_resolves_after_cooldown() {
+    // Test: trigger_and_wait() waits one cooldown in trailing mode and none in leading mode
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    let guard = debounce.trigger_and_wait().await;
+    assert_eq!(guard.coalesced_count(), 1);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    let start = time::Instant::now();
+    drop(debounce.trigger_and_wait().await);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn millis_and_secs_constructors() {
+    // Test: from_millis() and from_secs() set the cooldown from integer literals
+    let debounce = Debouncer::from_millis(250, DebounceMode::Trailing);
+    assert_eq!(debounce.cooldown(), Duration::from_millis(250));
+    let start = time::Instant::now();
+    drop(debounce.trigger_and_wait().await);
+    assert_eq!(start.elapsed(), Duration::from_millis(250));
+
+    let debounce = Debouncer::from_secs(2, DebounceMode::Leading);
+    assert_eq!(debounce.cooldown(), Duration::from_secs(2));
+    assert_eq!(debounce.mode(), DebounceMode::Leading);
+}
+
+#[tokio::test(start_paused = true)]
+async fn poll_ready_by_hand() {
+    // Test: poll_ready() stays pending through the cooldown and yields the guard afterwards
+    use futures_util::task::noop_waker_ref;
+    use std::task::{Context, Poll};
+
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let mut cx = Context::from_waker(noop_waker_ref());
+    assert!(debounce.poll_ready(&mut cx).is_pending());
+
+    debounce.trigger();
+    assert!(debounce.poll_ready(&mut cx).is_pending());
+    time::advance(Duration::from_millis(500)).await;
+    assert!(debounce.poll_ready(&mut cx).is_pending());
+    time::advance(Duration::from_millis(500)).await;
+    let guard = match debounce.poll_ready(&mut cx) {
+        Poll::Ready(guard) => guard,
+        Poll::Pending => panic!("ready after the cooldown"),
+    };
+    assert_eq!(guard.coalesced_count(), 1);
+    assert!(debounce.poll_ready(&mut cx).is_pending(), "the batch is claimed");
+    drop(guard);
+    assert!(!debounce.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn abort_leaves_trigger_pending() {
+    // Test: An aborted guard commits nothing, so the batch fires again on the next ready()
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger();
+    debounce.trigger();
+    let start = time::Instant::now();
+
+    let guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    guard.abort();
+    assert!(debounce.is_triggered());
+    assert_eq!(debounce.stats().fires, 0);
+    assert_eq!(debounce.last_fired(), None);
+
+    // Re-fires at once with the same coalesced batch
+    let guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(guard.coalesced_count(), 2);
+    drop(guard);
+    assert!(!debounce.is_triggered());
+    assert_eq!(debounce.stats().fires, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn arm_fires_next_trigger_within_cooldown() {
+    // Test: arm() lets the next leading trigger fire at once despite the cooldown, without triggering
+    let debounce = Debouncer::new(Duration::from_secs(10), DebounceMode::Leading);
+    let start = time::Instant::now();
+    debounce.trigger();
+    drop(debounce.ready().await);
+
+    time::advance(Duration::from_secs(1)).await;
+    debounce.arm();
+    assert!(!debounce.is_triggered(), "arm() does not trigger");
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(debounce.stats().fires, 2);
+
+    // Without re-arming the cooldown applies again
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(11));
+}
+
+#[tokio::test(start_paused = true)]
+async fn closed_resolves_when_closed() {
+    // Test: A spawned supervisor awaiting closed() wakes when the main task calls close()
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let supervisor = {
+        let debounce = debounce.clone();
+        tokio::spawn(async move {
+            debounce.closed().await;
+            time::Instant::now()
+        })
+    };
+
+    time::sleep(Duration::from_secs(3)).await;
+    assert!(!debounce.is_closed());
+    assert!(!supervisor.is_finished());
+    let closed_at = time::Instant::now();
+    debounce.close();
+    assert!(debounce.is_closed());
+    assert_eq!(supervisor.await.unwrap(), closed_at);
+
+    // Already closed: resolves at once
+    debounce.closed().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn guard_reports_ready_at() {
+    // Test: ready_at() is the end of the cooldown, even when the guard is taken later
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let triggered_at = time::Instant::now();
+    debounce.trigger();
+
+    time::advance(Duration::from_secs(3)).await;
+    let guard = debounce.ready().await;
+    assert_eq!(guard.ready_at(), triggered_at + Duration::from_secs(1));
+    assert_eq!(guard.ready_at().elapsed(), Duration::from_secs(2));
+    drop(guard);
+
+    // A leading fire is eligible as soon as it is triggered
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    let triggered_at = time::Instant::now();
+    debounce.trigger();
+    time::advance(Duration::from_millis(500)).await;
+    let guard = debounce.ready().await;
+    assert_eq!(guard.ready_at(), triggered_at);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn trigger_blocking_from_spawn_blocking() {
+    // Test: Triggers from spawn_blocking reach a waiter that is already parked in ready()
+    let debounce = Debouncer::new(Duration::from_millis(20), DebounceMode::Trailing);
+    let waiter = {
+        let debounce = debounce.clone();
+        tokio::spawn(async move { debounce.ready_owned().await.coalesced_count() })
+    };
+    // Let the waiter park first; it must not hold the state lock while waiting
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let trigger = debounce.clone();
+    tokio::task::spawn_blocking(move || {
+        for _ in 0..3 {
+            trigger.trigger_blocking();
+        }
+    })
+    .await
+    .unwrap();
+
+    let coalesced = time::timeout(Duration::from_secs(5), waiter)
+        .await
+        .expect("the batch fires")
+        .unwrap();
+    assert_eq!(coalesced, 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn zero_cooldown_fires_every_trigger_promptly() {
+    // Test: With a zero cooldown each trigger fires without any time passing, in every mode
+    for mode in [DebounceMode::Trailing, DebounceMode::Leading, DebounceMode::Both, DebounceMode::Throttle] {
+        let debounce = Debouncer::new(Duration::ZERO, mode);
+        let start = time::Instant::now();
+        for _ in 0..5 {
+            debounce.trigger();
+            let guard = debounce.ready().await;
+            assert_eq!(guard.coalesced_count(), 1, "{:?}", mode);
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO, "{:?}", mode);
+        assert_eq!(debounce.stats().fires, 5, "{:?}", mode);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn zero_cooldown_wakes_parked_waiter() {
+    // Test: A waiter parked before the trigger fires as soon as the event loop yields
+    let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Trailing);
+    let waiter = {
+        let debounce = debounce.clone();
+        tokio::spawn(async move { debounce.ready_owned().await.ready_at() })
+    };
+    tokio::task::yield_now().await;
+    let triggered_at = time::Instant::now();
+    debounce.trigger();
+    assert_eq!(waiter.await.unwrap(), triggered_at);
+}
+
+#[tokio::test(start_paused = true)]
+async fn sub_millisecond_cooldown_rounds_up_to_timer_tick() {
+    // Test: A microsecond cooldown waits at least its length but no more than one timer tick
+    let debounce = Debouncer::new(Duration::from_micros(500), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 2);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_micros(500), "{:?}", elapsed);
+    assert!(elapsed <= Debouncer::TIMER_RESOLUTION, "{:?}", elapsed);
+}
+
+#[tokio::test(start_paused = true)]
+async fn on_fire_runs_once_per_batch() {
+    // Test: The on_fire callback runs once per committed batch, not per trigger or abort
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let fired = Arc::new(AtomicUsize::new(0));
+    {
+        let fired = fired.clone();
+        debounce.on_fire(move || {
+            fired.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+
+    for _ in 0..3 {
+        debounce.trigger();
+        debounce.trigger();
+        drop(debounce.ready().await);
+    }
+    assert_eq!(fired.load(Ordering::SeqCst), 3);
+
+    debounce.trigger();
+    debounce.ready().await.abort();
+    assert_eq!(fired.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn debug_output() {
+    // Test: Debug shows the mode and flags, and never blocks on the state lock
+    use std::sync::{Arc, Mutex};
+
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger();
+    let output = format!("{:?}", debounce);
+    assert!(output.contains("Trailing"), "{}", output);
+    assert!(output.contains("triggered: true"), "{}", output);
+
+    let guard = debounce.ready().await;
+    let output = format!("{:?}", guard);
+    assert!(output.contains("coalesced: 1"), "{}", output);
+    drop(guard);
+
+    // The on_fire callback runs under the state lock
+    let seen = Arc::new(Mutex::new(String::new()));
+    {
+        let seen = seen.clone();
+        let weak = debounce.downgrade();
+        debounce.on_fire(move || {
+            if let Some(debounce) = weak.upgrade() {
+                *seen.lock().unwrap() = format!("{:?}", debounce);
+            }
+        });
+    }
+    debounce.trigger();
+    drop(debounce.ready().await);
+    let output = seen.lock().unwrap().clone();
+    // The mode lives behind the state lock, so only the unlocked output shows it
+    assert!(output.contains("Debouncer") && output.contains("<locked>"), "{}", output);
+    assert!(format!("{:?}", debounce).contains("Trailing"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn max_cooldown_never_fires_on_time_but_flushes() {
+    // Test: A Duration::MAX cooldown does not overflow; the batch only fires via flush()
+    for mode in [DebounceMode::Leading, DebounceMode::Both, DebounceMode::Throttle] {
+        // The leading edge still fires; the follow-up batch never does on time
+        let debounce = Debouncer::with_max_wait(Duration::MAX, mode, Duration::MAX);
+        drop(debounce.trigger_and_wait().await);
+        debounce.trigger();
+        debounce.trigger();
+        assert!(debounce.ready_timeout(Duration::from_secs(86400)).await.is_none(), "{:?}", mode);
+        debounce.flush();
+        assert!(debounce.try_ready().is_some(), "{:?}", mode);
+    }
+
+    let debounce = Debouncer::new(Duration::MAX, DebounceMode::Trailing);
+    debounce.trigger();
+    let mut yielded = false;
+    tokio::select! {
+        _ = debounce.ready() => { yielded = true; }
+        _ = time::sleep(Duration::from_secs(86400 * 365)) => {}
+    }
+    assert!(!yielded, "no fire on time");
+    assert!(matches!(debounce.status(), DebouncerStatus::Pending { .. }));
+
+    debounce.flush();
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn ready_as_leading_on_trailing_debouncer() {
+    // Test: ready_as(Leading) fires a trailing debouncer's first event immediately, once
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+    drop(debounce.ready_as(DebounceMode::Leading).await);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert_eq!(debounce.mode(), DebounceMode::Trailing);
+
+    // Plain ready() keeps the configured trailing behaviour
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn interval_stats_track_time_between_fires() {
+    // Test: Fires at known intervals produce the expected min, max and mean
+    use tokio_debouncer::IntervalStats;
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    assert_eq!(debounce.interval_stats(), IntervalStats::default());
+
+    for gap in [0, 2, 4, 3] {
+        time::advance(Duration::from_secs(gap)).await;
+        drop(debounce.trigger_and_wait().await);
+    }
+    let stats = debounce.interval_stats();
+    assert_eq!(stats.count, 3);
+    assert_eq!(stats.min, Duration::from_secs(2));
+    assert_eq!(stats.max, Duration::from_secs(4));
+    assert_eq!(stats.mean, Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_checked_reports_new_batch() {
+    // Test: Only the trigger that opens a batch returns true
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    assert!(debounce.trigger_checked());
+    assert!(!debounce.trigger_checked());
+    assert!(!debounce.trigger_checked());
+
+    drop(debounce.ready().await);
+    assert!(debounce.trigger_checked());
+
+    debounce.close();
+    assert!(!debounce.trigger_checked());
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_trigger_during_processing_fires_after_cooldown() {
+    // Test: A trigger arriving while the guard is held schedules another fire
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    debounce.trigger();
+    drop(guard);
+
+    assert!(debounce.is_triggered());
+    let start = time::Instant::now();
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 1);
+    assert_eq!(time::Instant::now() - start, Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn lead_tail_fires_immediately_and_after_burst() {
+    // Test: The lead-tail preset fires on the first trigger and once after the burst ends
+    let debounce = Debouncer::lead_tail(Duration::from_millis(100));
+    assert_eq!(debounce.mode(), DebounceMode::Both);
+    let start = time::Instant::now();
+
+    debounce.trigger();
+    drop(debounce.ready().await);
+    assert_eq!(time::Instant::now(), start);
+
+    for _ in 0..3 {
+        time::advance(Duration::from_millis(50)).await;
+        debounce.trigger();
+    }
+    drop(debounce.ready().await);
+    assert_eq!(time::Instant::now() - start, Duration::from_millis(250));
+    assert!(!debounce.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn ready_fair_serves_waiters_in_arrival_order() {
+    // Test: Waiters parked in order receive successive batches in that order, then rotate
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    for id in 0..3 {
+        let worker = debounce.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            loop {
+                let _guard = worker.ready_fair().await;
+                tx.send(id).unwrap();
+            }
+        });
+        tokio::task::yield_now().await;
+    }
+
+    let mut order = Vec::new();
+    for _ in 0..6 {
+        debounce.trigger();
+        order.push(rx.recv().await.unwrap());
+    }
+    assert_eq!(order, [0, 1, 2, 0, 1, 2]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn subscriber_counts_fires_without_consuming() {
+    // Test: A watcher sees every fire while a separate worker takes the guards
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let mut watcher = debounce.subscribe();
+    let observed = tokio::spawn(async move {
+        for _ in 0..3 {
+            watcher.next_fire().await;
+        }
+    });
+
+    for _ in 0..3 {
+        debounce.trigger();
+        let guard = debounce.ready().await;
+        assert_eq!(guard.coalesced_count(), 1);
+    }
+    observed.await.unwrap();
+    assert_eq!(debounce.stats().fires, 3);
+}
+
+#[tokio::test]
+async fn clones_share_identity() {
+    // Test: Clones and upgraded weak handles share an id; distinct debouncers differ
+    let a = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let b = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let a2 = a.clone();
+    assert_eq!(a.id(), a2.id());
+    assert!(a.ptr_eq(&a2));
+    assert_eq!(a.downgrade().upgrade().unwrap().id(), a.id());
+    assert_ne!(a.id(), b.id());
+    assert!(!a.ptr_eq(&b));
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_in_starts_window_after_delay() {
+    // Test: trigger_in fires delay + cooldown after the call, and later triggers keep that deadline
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger_in(Duration::from_secs(5));
+
+    time::advance(Duration::from_secs(2)).await;
+    debounce.trigger();
+    assert_eq!(debounce.time_until_ready(), Some(Duration::from_secs(4)));
+
+    drop(debounce.ready().await);
+    assert_eq!(time::Instant::now() - start, Duration::from_secs(6));
+}
+
+#[tokio::test(start_paused = true)]
+async fn commit_finalizes_immediately() {
+    // Test: commit() finalizes the batch without waiting for the guard's scope to end
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    debounce.trigger();
+    let guard = debounce.ready().await;
+    guard.commit();
+    assert!(!debounce.is_triggered());
+    assert_eq!(debounce.stats().fires, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn rate_limited_caps_fires_per_window() {
+    // Test: Under a heavy trigger stream at most `max` fires land in any sliding window
+    let mode = DebounceMode::RateLimited { max: 3, window: Duration::from_secs(1) };
+    let debounce = Debouncer::new(Duration::from_millis(100), mode);
+    let trigger = debounce.clone();
+    let producer = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    let start = time::Instant::now();
+    let mut fires = Vec::new();
+    while start.elapsed() < Duration::from_secs(5) {
+        drop(debounce.ready().await);
+        fires.push(time::Instant::now());
+    }
+    producer.abort();
+
+    // The first three fire right away, then three more each time the window slides on
+    assert!(fires[2] - start < Duration::from_millis(100));
+    for pair in fires.windows(4) {
+        assert!(pair[3] - pair[0] >= Duration::from_secs(1), "four fires within one window");
+    }
+    assert!(fires.len() >= 15, "only {} fires in 5s", fires.len());
+}
+
+#[tokio::test]
+async fn try_trigger_reports_closed() {
+    // Test: try_trigger succeeds while open and fails once closed
+    use tokio_debouncer::ClosedError;
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    assert_eq!(debounce.try_trigger(), Ok(()));
+    assert_eq!(debounce.try_trigger(), Ok(()));
+    debounce.close();
+    assert_eq!(debounce.try_trigger(), Err(ClosedError));
+    // The rejected trigger is not counted
+    assert_eq!(debounce.stats().triggers, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_continuous_triggers_fire_on_cooldown_multiples() {
+    // Test: Continuous triggers in leading mode fire at exact cooldown multiples despite slow processing
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    let trigger = debounce.clone();
+    let producer = tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    let start = time::Instant::now();
+    for n in 0..5 {
+        let guard = debounce.ready().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(n), "fire {} drifted", n);
+        // Processing time must not push the next fire back
+        time::sleep(Duration::from_millis(50)).await;
+        drop(guard);
+    }
+    producer.abort();
+}
+
+#[tokio::test(start_paused = true)]
+async fn wait_idle_resolves_after_cooldown_tail() {
+    // Test: wait_idle waits out the pending batch and the cooldown after its fire
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Leading);
+    debounce.wait_idle().await;
+
+    let start = time::Instant::now();
+    debounce.trigger();
+    let worker = debounce.clone();
+    tokio::spawn(async move {
+        let _guard = worker.ready_owned().await;
+        time::sleep(Duration::from_millis(200)).await;
+    });
+
+    debounce.wait_idle().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert!(!debounce.is_triggered());
+    assert_eq!(debounce.stats().fires, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn reschedule_fires_follow_up_after_delay() {
+    // Test: A rescheduled guard fires again after the given delay without a new trigger
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger();
+    let start = time::Instant::now();
+    let guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    guard.reschedule(Duration::from_secs(5));
+    assert!(debounce.is_triggered());
+
+    let guard = debounce.ready().await;
+    assert_eq!(start.elapsed(), Duration::from_secs(6));
+    assert_eq!(guard.coalesced_count(), 0);
+    drop(guard);
+    assert!(!debounce.is_triggered(), "a plain drop does not perpetuate");
+}
+
+#[tokio::test(start_paused = true)]
+async fn pipe_to_sends_one_message_per_batch() {
+    // Test: The piped channel receives one message per batch and the task ends with the receiver
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+    let task = debounce.pipe_to(tx);
+
+    for _ in 0..3 {
+        debounce.trigger();
+        debounce.trigger();
+        time::sleep(Duration::from_millis(500)).await;
+    }
+    let mut received = 0;
+    while rx.try_recv().is_ok() {
+        received += 1;
+    }
+    assert_eq!(received, 3);
+
+    drop(rx);
+    task.await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn trailing_trigger_during_sleep_extends_deadline() {
+    // Test: A trigger while ready() sleeps moves the fire to the extended deadline, never the original
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move {
+        let _guard = waiter.ready_owned().await;
+        time::Instant::now()
+    });
+    time::sleep(Duration::from_millis(600)).await;
+    debounce.trigger();
+
+    time::sleep(Duration::from_millis(500)).await;
+    assert!(!handle.is_finished(), "must not fire at the original deadline");
+    let fired = handle.await.unwrap();
+    assert_eq!(fired - start, Duration::from_millis(1600));
+}
+
+#[tokio::test(start_paused = true)]
+async fn guard_outlives_its_debouncer_handle() {
+    // Test: A guard stays valid and commits after every Debouncer handle is dropped
+    let (guard, mut watcher) = {
+        let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+        let watcher = debounce.subscribe();
+        debounce.trigger();
+        (debounce.ready().await, watcher)
+    };
+    assert_eq!(guard.coalesced_count(), 1);
+    drop(guard);
+    watcher.next_fire().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn reset_stats_zeroes_counters() {
+    // Test: reset_stats zeroes the counters without touching pending state
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    debounce.trigger();
+    drop(debounce.ready().await);
+    debounce.trigger();
+    debounce.reset_stats();
+    assert_eq!(debounce.stats().triggers, 0);
+    assert_eq!(debounce.stats().fires, 0);
+    assert!(debounce.is_triggered());
+
+    drop(debounce.ready().await);
+    assert_eq!(debounce.stats().fires, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn ready_with_info_describes_batch() {
+    // Test: BatchInfo reports the trigger count, first trigger and readiness instant of a known burst
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    for _ in 0..3 {
+        debounce.trigger();
+        time::advance(Duration::from_millis(300)).await;
+    }
+    let (guard, info) = debounce.ready_with_info().await;
+    assert_eq!(info.coalesced, 3);
+    assert_eq!(info.first_trigger, start);
+    assert_eq!(info.ready_at, start + Duration::from_millis(1600));
+    assert_eq!(guard.coalesced_count(), info.coalesced);
+    assert_eq!(guard.ready_at(), info.ready_at);
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_during_held_guard_keeps_its_arrival_time() {
+    // Test: A batch opened while a guard is held counts max_wait and first_trigger from its own
+    // first trigger, not from when the guard was dropped
+    let debounce = Debouncer::with_max_wait(Duration::from_secs(1), DebounceMode::Trailing, Duration::from_secs(2));
+    let start = time::Instant::now();
+    debounce.trigger();
+    {
+        let _guard = debounce.ready().await;
+        time::advance(Duration::from_millis(100)).await;
+        debounce.trigger();
+        time::advance(Duration::from_millis(2500)).await;
+        debounce.trigger();
+        time::advance(Duration::from_millis(500)).await;
+    }
+
+    // The cooldown would run to 4.6s, but max_wait already expired at 3.1s
+    let (_guard, info) = debounce.ready_with_info().await;
+    assert_eq!(info.first_trigger, start + Duration::from_millis(1100));
+    assert_eq!(start.elapsed(), Duration::from_millis(4100));
+    assert_eq!(info.coalesced, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn set_mode_switches_timing_at_runtime() {
+    // Test: A waiting ready() follows a mode switch, and later batches use the new mode's timing
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+    let waiter = debounce.clone();
+    let handle = tokio::spawn(async move {
+        let _guard = waiter.ready_owned().await;
+        time::Instant::now()
+    });
+    time::sleep(Duration::from_millis(300)).await;
+
+    // Leading fires an un-run debouncer's pending trigger right away
+    debounce.set_mode(DebounceMode::Leading);
+    assert_eq!(debounce.mode(), DebounceMode::Leading);
+    assert_eq!(handle.await.unwrap() - start, Duration::from_millis(300));
+
+    // Back to trailing: each trigger restarts the cooldown again
+    debounce.set_mode(DebounceMode::Trailing);
+    time::sleep(Duration::from_secs(2)).await;
+    debounce.trigger();
+    time::sleep(Duration::from_millis(500)).await;
+    debounce.trigger();
+    let triggered = time::Instant::now();
+    drop(debounce.ready().await);
+    assert_eq!(triggered.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn pause_holds_fires_until_resume() {
+    // Test: A paused debouncer accumulates triggers but never fires; resume fires the due batch at once
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let waiter = {
+        let debounce = debounce.clone();
+        tokio::spawn(async move { debounce.ready_owned().await.coalesced_count() })
+    };
+    tokio::task::yield_now().await;
+
+    debounce.pause();
+    assert!(debounce.is_paused());
+    debounce.trigger();
+    debounce.trigger();
+    time::sleep(Duration::from_secs(5)).await;
+    assert!(!waiter.is_finished());
+    assert!(debounce.try_ready().is_none());
+    assert_eq!(debounce.time_until_ready(), None);
+
+    debounce.resume();
+    let start = time::Instant::now();
+    assert_eq!(waiter.await.unwrap(), 2);
+    // The cooldown elapsed during the pause, so the batch fires without further waiting
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    assert!(!debounce.is_paused());
+}
+
+#[tokio::test(start_paused = true)]
+async fn waiters_counts_parked_ready_calls() {
+    // Test: The waiter count tracks parked ready() calls, including cancelled ones
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    assert_eq!(debounce.waiters(), 0);
+    let tasks: Vec<_> = (0..3)
+        .map(|_| {
+            let debounce = debounce.clone();
+            tokio::spawn(async move {
+                debounce.ready_owned().await;
+            })
+        })
+        .collect();
+    tokio::task::yield_now().await;
+    assert_eq!(debounce.waiters(), 3);
+
+    // A timed-out wait stops counting when it is dropped
+    assert!(debounce.ready_timeout(Duration::from_millis(10)).await.is_none());
+    assert_eq!(debounce.waiters(), 3);
+
+    // Each batch releases one waiter
+    for _ in 0..3 {
+        debounce.trigger();
+        time::sleep(Duration::from_millis(150)).await;
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+    assert_eq!(debounce.waiters(), 0);
+}
+
+#[tokio::test]
+async fn config_reports_constructed_parameters() {
+    // Test: config() mirrors the constructor arguments and follows runtime changes
+    use tokio_debouncer::DebouncerSettings;
+
+    let debounce = Debouncer::with_max_wait(Duration::from_millis(250), DebounceMode::Leading, Duration::from_secs(1));
+    let settings = |cooldown, mode| DebouncerSettings { cooldown, mode, max_wait: Some(Duration::from_secs(1)) };
+    assert_eq!(debounce.config(), settings(Duration::from_millis(250), DebounceMode::Leading));
+
+    debounce.set_mode(DebounceMode::Trailing);
+    debounce.set_cooldown(Duration::from_millis(500));
+    let config = debounce.config();
+    assert_eq!(config, settings(Duration::from_millis(500), DebounceMode::Trailing));
+    assert_eq!(config.builder().build().unwrap().config(), config);
+
+    let default = Debouncer::default().config();
+    assert_eq!(default.cooldown, Debouncer::DEFAULT_COOLDOWN);
+    assert_eq!(default.mode, DebounceMode::Trailing);
+    assert_eq!(default.max_wait, None);
+}
+
+#[tokio::test]
+async fn config_keeps_sub_millisecond_durations() {
+    // Test: config() is lossless, so debouncers differing below a millisecond don't compare equal
+    let fine = Debouncer::new(Duration::from_micros(1500), DebounceMode::Trailing);
+    let coarse = Debouncer::new(Duration::from_millis(1), DebounceMode::Trailing);
+    assert_eq!(fine.config().cooldown, Duration::from_micros(1500));
+    assert_ne!(fine.config(), coarse.config());
+    let sub_ms = Debouncer::new(Duration::from_micros(300), DebounceMode::Trailing);
+    assert_eq!(sub_ms.config().cooldown, Duration::from_micros(300));
+    assert_eq!(fine.config().builder().build().unwrap().cooldown(), Duration::from_micros(1500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_on_watch_fires_once_per_quiet_period() {
+    // Test: Bursts of watched value changes coalesce into one batch each; the task ends with the sender
+    use tokio::sync::watch;
+
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let (tx, rx) = watch::channel(0);
+    let task = debounce.trigger_on_watch(rx);
+
+    for burst in 0..2 {
+        for n in 1..=4 {
+            tx.send(burst * 10 + n).unwrap();
+            time::sleep(Duration::from_millis(20)).await;
+        }
+        let guard = debounce.ready().await;
+        assert_eq!(guard.coalesced_count(), 4);
+        drop(guard);
+        assert!(!debounce.is_triggered());
+    }
+    assert_eq!(debounce.stats().fires, 2);
+
+    drop(tx);
+    task.await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn with_state_can_open_and_discard_batches() {
+    // Test: Setting triggered through with_state opens a batch for ready(); clearing it discards one
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    let waiter = {
+        let debounce = debounce.clone();
+        tokio::spawn(async move { debounce.ready_owned().await.coalesced_count() })
+    };
+    tokio::task::yield_now().await;
+
+    let start = time::Instant::now();
+    let was_triggered = debounce.with_state(|view| {
+        let was = view.triggered;
+        view.triggered = true;
+        view.coalesced = 5;
+        view.last_run = time::Instant::now();
+        was
+    });
+    assert!(!was_triggered);
+    assert_eq!(waiter.await.unwrap(), 5);
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+    assert!(debounce.with_state(|view| view.has_run));
+
+    debounce.trigger();
+    debounce.with_state(|view| view.triggered = false);
+    assert!(!debounce.is_triggered());
+    assert!(debounce.ready_timeout(Duration::from_secs(1)).await.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stress_concurrent_triggers_from_clones_are_never_lost() {
+    // Test: Triggers racing in from many threads land in exactly one batch each, with no double fires
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    const THREADS: u64 = 8;
+    const TRIGGERS: u64 = 2000;
+    let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Throttle);
+    let delivered = Arc::new(AtomicU64::new(0));
+    let batches = Arc::new(AtomicU64::new(0));
+
+    let mut consumers = Vec::new();
+    for _ in 0..3 {
+        let waiter = debounce.clone();
+        let delivered = delivered.clone();
+        let batches = batches.clone();
+        consumers.push(tokio::spawn(async move {
+            while let Some(guard) = waiter.ready_or_closed().await {
+                delivered.fetch_add(guard.coalesced_count(), Ordering::SeqCst);
+                batches.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+    }
+    let producers: Vec<_> = (0..THREADS)
+        .map(|i| {
+            let trigger = debounce.clone();
+            std::thread::spawn(move || {
+                for n in 0..TRIGGERS {
+                    trigger.trigger();
+                    if (n + i) % 64 == 0 {
+                        std::thread::yield_now();
+                    }
+                }
+            })
+        })
+        .collect();
+    for producer in producers {
+        producer.join().expect("a trigger thread panicked");
+    }
+
+    // Deliver the tail and let the consumers finish
+    debounce.flush();
+    debounce.close();
+    time::timeout(Duration::from_secs(10), async {
+        for consumer in consumers {
+            consumer.await.unwrap();
+        }
+    })
+    .await
+    .expect("consumers drain every batch");
+
+    assert_eq!(delivered.load(Ordering::SeqCst), THREADS * TRIGGERS);
+    assert_eq!(debounce.stats().fires, batches.load(Ordering::SeqCst));
+    assert_eq!(debounce.stats().triggers, THREADS * TRIGGERS);
+}
+
+#[tokio::test(start_paused = true)]
+async fn install_shutdown_flushes_then_closes() {
+    // Test: The shutdown signal delivers the pending batch immediately and then closes the debouncer
+    use tokio::sync::oneshot;
+
+    let debounce = Debouncer::new(Duration::from_secs(10), DebounceMode::Trailing);
+    let (tx, rx) = oneshot::channel::<()>();
+    let shutdown = debounce.install_shutdown(async move {
+        let _ = rx.await;
+    });
+    debounce.trigger();
+    debounce.trigger();
+
+    let start = time::Instant::now();
+    tx.send(()).unwrap();
+    shutdown.await.unwrap();
+    assert!(debounce.is_closed());
+
+    let guard = debounce.ready_or_closed().await.expect("final batch is delivered");
+    assert_eq!(guard.coalesced_count(), 2);
+    drop(guard);
+    assert_eq!(start.elapsed(), Duration::ZERO, "no cooldown wait after shutdown");
+    assert!(debounce.ready_or_closed().await.is_none());
+    assert!(debounce.try_trigger().is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_keyed_reports_keys_per_batch() {
+    // Test: Keys triggered in a burst are all reported by the guard, once each, and reset per batch
+    let debounce = Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    debounce.trigger_keyed("config");
+    debounce.trigger_keyed("routes");
+    debounce.trigger_keyed("config");
+    debounce.trigger();
+
+    let guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 4);
+    assert_eq!(guard.keys::<&str>(), vec!["config", "routes"]);
+    assert!(guard.keys::<u32>().is_empty(), "keys of other types are skipped");
+
+    // Keys arriving while the guard is held belong to the next batch
+    debounce.trigger_keyed(7u32);
+    drop(guard);
+    let guard = debounce.ready_owned().await;
+    assert_eq!(guard.keys::<u32>(), vec![7]);
+    assert!(guard.keys::<&str>().is_empty());
+
+    // An aborted batch keeps its keys
+    guard.abort();
+    debounce.trigger_keyed(8u32);
+    let guard = debounce.ready().await;
+    assert_eq!(guard.keys::<u32>(), vec![7, 8]);
+}
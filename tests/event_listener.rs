@@ -0,0 +1,36 @@
+// tests/event_listener.rs
+#![cfg(feature = "event-listener")]
+
+use tokio::time::{self, Duration};
+use tokio_debouncer::{DebounceMode, Debouncer};
+
+#[tokio::test(start_paused = true)]
+async fn trigger_then_ready_with_event_listener() {
+    // Test: The basic trigger -> ready flow works with the event-listener notifier
+    let debouncer = Debouncer::new(Duration::from_millis(50), DebounceMode::Trailing);
+    let waiter = {
+        let debouncer = debouncer.clone();
+        tokio::spawn(async move {
+            let guard = debouncer.ready_owned().await;
+            guard.commit();
+        })
+    };
+    tokio::task::yield_now().await;
+    debouncer.trigger();
+    time::advance(Duration::from_millis(60)).await;
+    waiter.await.unwrap();
+    assert!(!debouncer.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn close_wakes_waiters_with_event_listener() {
+    // Test: Closing wakes a pending waiter through the event-listener notifier
+    let debouncer = Debouncer::new(Duration::from_millis(50), DebounceMode::Trailing);
+    let waiter = {
+        let debouncer = debouncer.clone();
+        tokio::spawn(async move { debouncer.closed().await })
+    };
+    tokio::task::yield_now().await;
+    debouncer.close();
+    waiter.await.unwrap();
+}
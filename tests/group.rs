@@ -0,0 +1,39 @@
+// tests/group.rs
+
+use tokio_debouncer::{DebounceMode, Debouncer, DebouncerGroup};
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn group_reports_index_of_ready_member() {
+    // Test: Members firing at staggered times resolve with their own index
+    let fast = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let slow = Debouncer::new(Duration::from_secs(3), DebounceMode::Trailing);
+    let mut group = DebouncerGroup::new();
+    assert_eq!(group.push(fast.clone()), 0);
+    assert_eq!(group.push(slow.clone()), 1);
+    assert_eq!(group.len(), 2);
+
+    let start = time::Instant::now();
+    fast.trigger();
+    slow.trigger();
+    let (index, guard) = group.ready().await;
+    assert_eq!(index, 0);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    drop(guard);
+
+    let (index, guard) = group.ready().await;
+    assert_eq!(index, 1);
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+    drop(guard);
+    assert!(!fast.is_triggered());
+    assert!(!slow.is_triggered());
+}
+
+#[tokio::test(start_paused = true)]
+async fn empty_group_never_resolves() {
+    // Test: ready() on an empty group stays pending
+    let group = DebouncerGroup::new();
+    assert!(group.is_empty());
+    let result = time::timeout(Duration::from_secs(10), group.ready()).await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,35 @@
+// tests/harness.rs
+#![cfg(feature = "test-util")]
+
+use tokio::time::Duration;
+use tokio_debouncer::{DebounceMode, Debouncer, DebouncerTestHarness};
+
+#[tokio::test(start_paused = true)]
+async fn harness_trailing_waits_for_quiet_period() {
+    // Test: In trailing mode the harness reports ready only once the cooldown has passed since the last trigger
+    let harness = DebouncerTestHarness::new(Debouncer::new(Duration::from_millis(100), DebounceMode::Trailing));
+    assert!(!harness.advance_and_check(Duration::from_millis(200)).await);
+
+    harness.debouncer().trigger();
+    assert!(!harness.advance_and_check(Duration::from_millis(60)).await);
+    harness.debouncer().trigger();
+    assert!(!harness.advance_and_check(Duration::from_millis(60)).await);
+    assert!(harness.advance_and_check(Duration::from_millis(40)).await);
+
+    // Checking does not claim the batch
+    harness.debouncer().try_ready().expect("batch is ready").commit();
+    assert!(!harness.advance_and_check(Duration::from_millis(200)).await);
+}
+
+#[tokio::test(start_paused = true)]
+async fn harness_leading_fires_immediately_then_cools_down() {
+    // Test: In leading mode the first trigger is ready at once and the next waits out the cooldown
+    let harness = DebouncerTestHarness::new(Debouncer::new(Duration::from_millis(100), DebounceMode::Leading));
+    harness.debouncer().trigger();
+    assert!(harness.advance_and_check(Duration::ZERO).await);
+    harness.debouncer().try_ready().expect("leading batch").commit();
+
+    harness.debouncer().trigger();
+    assert!(!harness.advance_and_check(Duration::from_millis(50)).await);
+    assert!(harness.advance_and_check(Duration::from_millis(50)).await);
+}
@@ -0,0 +1,61 @@
+// tests/keyed.rs
+
+use tokio_debouncer::DebounceMode;
+use tokio_debouncer::KeyedDebouncer;
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn keys_fire_in_readiness_order() {
+    // Test: Two keys triggered at different times are yielded in the order they become ready
+    let debounce = KeyedDebouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+    let start = time::Instant::now();
+
+    debounce.trigger("a");
+    time::advance(Duration::from_secs(2)).await;
+    debounce.trigger("b");
+
+    let (key, guard) = debounce.ready().await;
+    assert_eq!(key, "a");
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+    drop(guard);
+
+    let (key, _guard) = debounce.ready().await;
+    assert_eq!(key, "b");
+    assert_eq!(start.elapsed(), Duration::from_secs(7));
+}
+
+#[tokio::test(start_paused = true)]
+async fn new_key_wakes_pending_ready() {
+    // Test: A key created while ready() is waiting is picked up without re-calling ready()
+    let debounce = KeyedDebouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+
+    let trigger = debounce.clone();
+    tokio::spawn(async move {
+        time::sleep(Duration::from_secs(3)).await;
+        trigger.trigger(7u32);
+    });
+
+    let (key, guard) = debounce.ready().await;
+    assert_eq!(key, 7);
+    assert_eq!(guard.coalesced_count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn idle_keys_are_evicted() {
+    // Test: Keys without pending triggers are evicted after the idle timeout
+    let debounce = KeyedDebouncer::with_idle_timeout(
+        Duration::from_secs(1),
+        DebounceMode::Leading,
+        Duration::from_secs(10),
+    );
+
+    debounce.trigger("a");
+    let (key, guard) = debounce.ready().await;
+    assert_eq!(key, "a");
+    drop(guard);
+    assert_eq!(debounce.len(), 1);
+
+    time::advance(Duration::from_secs(11)).await;
+    debounce.trigger("b");
+    assert_eq!(debounce.len(), 1, "idle key a was evicted");
+}
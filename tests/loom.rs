@@ -0,0 +1,91 @@
+// tests/loom.rs
+//
+// Model-checks the lock protocol between trigger, claim and finalize on the shared state.
+// Run with:
+//     RUSTFLAGS="--cfg debouncer_loom" cargo test --release --test loom
+#![cfg(debouncer_loom)]
+
+use loom::thread;
+use tokio::time::Duration;
+use tokio_debouncer::{DebounceMode, Debouncer};
+
+/// Claim and commit every batch that is ready right now, returning the triggers delivered.
+fn drain(debounce: &Debouncer) -> (u64, u64) {
+    let mut delivered = 0;
+    let mut batches = 0;
+    while let Some(guard) = debounce.try_ready() {
+        delivered += guard.coalesced_count();
+        batches += 1;
+    }
+    (delivered, batches)
+}
+
+#[test]
+fn concurrent_triggers_are_delivered_exactly_once() {
+    // Test: Under every interleaving of two triggering clones and a consumer, each trigger lands in exactly one batch
+    loom::model(|| {
+        let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Throttle);
+        let producers: Vec<_> = (0..2)
+            .map(|_| {
+                let trigger = debounce.clone();
+                thread::spawn(move || trigger.trigger())
+            })
+            .collect();
+        let consumer = {
+            let consumer = debounce.clone();
+            thread::spawn(move || drain(&consumer))
+        };
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        let (mut delivered, mut batches) = consumer.join().unwrap();
+        let (rest, rest_batches) = drain(&debounce);
+        delivered += rest;
+        batches += rest_batches;
+
+        assert_eq!(delivered, 2);
+        assert_eq!(debounce.stats().fires, batches);
+        assert!(!debounce.is_triggered());
+    });
+}
+
+#[test]
+fn racing_claims_hand_out_one_guard_per_batch() {
+    // Test: Two consumers racing for one pending batch never both receive a guard for it
+    loom::model(|| {
+        let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Trailing);
+        debounce.trigger();
+        let consumers: Vec<_> = (0..2)
+            .map(|_| {
+                let consumer = debounce.clone();
+                thread::spawn(move || consumer.try_ready().map(|guard| guard.coalesced_count()))
+            })
+            .collect();
+        let claimed: Vec<_> = consumers.into_iter().filter_map(|c| c.join().unwrap()).collect();
+
+        assert_eq!(claimed, vec![1]);
+        assert_eq!(debounce.stats().fires, 1);
+    });
+}
+
+#[test]
+fn trigger_during_processing_stays_pending() {
+    // Test: A trigger racing the guard drop is either part of the batch or left pending, never lost
+    loom::model(|| {
+        let debounce = Debouncer::new(Duration::ZERO, DebounceMode::Throttle);
+        debounce.trigger();
+        let guard = debounce.try_ready().expect("the first trigger is ready");
+        let producer = {
+            let trigger = debounce.clone();
+            thread::spawn(move || trigger.trigger())
+        };
+        let first = guard.coalesced_count();
+        drop(guard);
+        producer.join().unwrap();
+        let (rest, _) = drain(&debounce);
+
+        assert_eq!(first, 1);
+        assert_eq!(rest, 1);
+    });
+}
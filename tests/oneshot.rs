@@ -0,0 +1,67 @@
+// tests/oneshot.rs
+
+use tokio_debouncer::{DebounceMode, OneShotDebouncer};
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn one_shot_fires_once_then_reports_done() {
+    // Test: The first burst fires once; afterwards ready() resolves to None immediately
+    let debounce = OneShotDebouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    debounce.trigger();
+    debounce.trigger();
+
+    let guard = debounce.ready().await.expect("first batch fires");
+    assert_eq!(guard.coalesced_count(), 2);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    drop(guard);
+    assert!(debounce.is_fired());
+
+    debounce.trigger();
+    assert!(debounce.ready().await.is_none());
+    assert_eq!(start.elapsed(), Duration::from_secs(1), "no waiting after the fire");
+}
+
+#[tokio::test(start_paused = true)]
+async fn one_shot_releases_concurrent_waiters() {
+    // Test: With two waiters one gets the guard and the other resolves to None
+    let debounce = OneShotDebouncer::new(Duration::from_millis(100), DebounceMode::Trailing);
+    debounce.trigger();
+    let other = debounce.clone();
+    let waiter = tokio::spawn(async move { other.ready().await.is_some() });
+    let mine = debounce.ready().await.is_some();
+    let theirs = waiter.await.unwrap();
+    assert!(mine ^ theirs);
+}
+
+#[tokio::test(start_paused = true)]
+async fn latch_fires_only_for_the_first_burst() {
+    // Test: A latch coalesces the first burst into one fire and ignores a later burst entirely
+    let latch = OneShotDebouncer::latch(Duration::from_millis(100));
+    let waiter = {
+        let latch = latch.clone();
+        tokio::spawn(async move {
+            let mut fires = Vec::new();
+            while let Some(guard) = latch.ready().await {
+                fires.push(guard.coalesced_count());
+            }
+            fires
+        })
+    };
+
+    for _ in 0..3 {
+        latch.trigger();
+        time::sleep(Duration::from_millis(30)).await;
+    }
+    time::sleep(Duration::from_millis(500)).await;
+    assert!(latch.is_fired());
+
+    // The second burst arrives after the quiet period and never fires
+    for _ in 0..3 {
+        latch.trigger();
+        time::sleep(Duration::from_millis(30)).await;
+    }
+    time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(waiter.await.unwrap(), vec![3]);
+    assert!(latch.ready().await.is_none());
+}
@@ -0,0 +1,92 @@
+// tests/runner.rs
+#![cfg(feature = "runner")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio_debouncer::{DebounceMode, Debouncer, DebouncerRunner};
+use tokio_util::sync::CancellationToken;
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn runner_invokes_handler_once_per_batch_until_cancelled() {
+    // Test: The runner calls the handler once per batch and stops on cancellation
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let cancel = CancellationToken::new();
+    let runner = DebouncerRunner::new(debounce.clone(), {
+        let calls = calls.clone();
+        move || {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    });
+    let worker = tokio::spawn(runner.run(cancel.clone()));
+
+    for _ in 0..3 {
+        // A burst of triggers is one batch
+        debounce.trigger();
+        debounce.trigger();
+        time::sleep(Duration::from_secs(2)).await;
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    cancel.cancel();
+    worker.await.unwrap();
+    debounce.trigger();
+    time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn runner_stops_when_debouncer_closes() {
+    // Test: Closing the debouncer ends run() after the pending batch is handled
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let runner = DebouncerRunner::new(debounce.clone(), {
+        let calls = calls.clone();
+        move || {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    });
+    let worker = tokio::spawn(runner.run(CancellationToken::new()));
+
+    debounce.trigger();
+    debounce.close();
+    worker.await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn runner_drains_pending_batch_on_stop() {
+    // Test: With run_until_idle_on_stop, a pending batch is handled before run() returns
+    let debounce = Debouncer::new(Duration::from_secs(10), DebounceMode::Trailing);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let cancel = CancellationToken::new();
+    let runner = DebouncerRunner::new(debounce.clone(), {
+        let calls = calls.clone();
+        move || {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    })
+    .run_until_idle_on_stop(true);
+    let worker = tokio::spawn(runner.run(cancel.clone()));
+
+    debounce.trigger();
+    time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let start = time::Instant::now();
+    cancel.cancel();
+    worker.await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(time::Instant::now(), start, "The final batch skips the cooldown");
+    assert!(!debounce.is_triggered());
+}
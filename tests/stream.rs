@@ -0,0 +1,87 @@
+// tests/stream.rs
+#![cfg(feature = "stream")]
+
+use futures_util::StreamExt;
+use tokio_debouncer::DebounceMode;
+use tokio_debouncer::Debouncer;
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn stream_yields_one_guard_per_batch() {
+    // Test: The stream yields a guard per batch under a steady trigger source
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    let trigger = debounce.clone();
+    tokio::spawn(async move {
+        loop {
+            trigger.trigger();
+            time::sleep(Duration::from_secs(2)).await;
+        }
+    });
+
+    let start = time::Instant::now();
+    let mut stream = debounce.into_stream();
+    let mut batches = 0;
+    while let Some(guard) = stream.next().await {
+        assert_eq!(guard.coalesced_count(), 1);
+        batches += 1;
+        if batches == 3 {
+            break;
+        }
+    }
+    assert_eq!(batches, 3);
+    assert_eq!(start.elapsed(), Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_stream_emits_last_item_per_burst() {
+    // Test: A debounced stream yields the last item of each burst and flushes when upstream ends
+    use futures_util::stream;
+    use tokio_debouncer::debounce_stream;
+
+    let start = time::Instant::now();
+    let events = stream::iter(vec![(0, 1), (100, 2), (100, 3), (2000, 4)]).then(|(delay, item)| async move {
+        time::sleep(Duration::from_millis(delay)).await;
+        item
+    });
+    let mut debounced = debounce_stream(events, Duration::from_secs(1), DebounceMode::Trailing);
+
+    assert_eq!(debounced.next().await, Some(3));
+    assert_eq!(start.elapsed(), Duration::from_millis(1200));
+    // The upstream ends right after the last item, so it is flushed without waiting
+    assert_eq!(debounced.next().await, Some(4));
+    assert_eq!(start.elapsed(), Duration::from_millis(2200));
+    assert_eq!(debounced.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_stream_burst_collapses_to_one_item() {
+    // Test: A burst from an upstream that then ends produces a single item
+    use futures_util::stream;
+    use tokio_debouncer::debounce_stream;
+
+    let debounced = debounce_stream(stream::iter(1..=10), Duration::from_secs(1), DebounceMode::Trailing);
+    assert_eq!(debounced.collect::<Vec<_>>().await, vec![10]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn from_receiver_batches_a_burst() {
+    // Test: A burst of channel items is emitted as one Vec after the cooldown
+    use tokio::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel(16);
+    let mut batches = Debouncer::from_receiver(rx, Duration::from_secs(1), DebounceMode::Trailing);
+    let start = time::Instant::now();
+    for n in 1..=5 {
+        tx.send(n).await.unwrap();
+    }
+
+    assert_eq!(batches.next().await, Some(vec![1, 2, 3, 4, 5]));
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    // Closing the channel flushes what is left without waiting, then ends the stream
+    tx.send(6).await.unwrap();
+    drop(tx);
+    assert_eq!(batches.next().await, Some(vec![6]));
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(batches.next().await, None);
+}
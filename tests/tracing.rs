@@ -0,0 +1,46 @@
+// tests/tracing.rs
+#![cfg(feature = "tracing")]
+
+use tokio_debouncer::DebounceMode;
+use tokio_debouncer::Debouncer;
+use tokio::time::Duration;
+use tracing_test::traced_test;
+
+#[tokio::test(start_paused = true)]
+#[traced_test]
+async fn emits_events_for_trigger_ready_drop_cycle() {
+    // Test: trigger, cooldown wait and commit each emit an event with mode and coalesced count
+    let debounce = Debouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger();
+    debounce.trigger();
+    {
+        let _guard = debounce.ready().await;
+    }
+
+    assert!(logs_contain("debouncer triggered"));
+    assert!(logs_contain("debouncer waiting for cooldown"));
+    assert!(logs_contain("debouncer batch committed"));
+    assert!(logs_contain("mode=Trailing"));
+    assert!(logs_contain("coalesced=2"));
+}
+
+#[tokio::test(start_paused = true)]
+#[traced_test]
+async fn warns_when_cooldown_is_below_timer_resolution() {
+    // Test: A sub-millisecond cooldown logs a warning, at construction and via set_cooldown
+    let debounce = Debouncer::new(Duration::from_micros(500), DebounceMode::Trailing);
+    assert!(logs_contain("below the timer resolution"));
+    assert!(logs_contain("cooldown=500µs"));
+
+    debounce.set_cooldown(Duration::from_micros(250));
+    assert!(logs_contain("cooldown=250µs"));
+}
+
+#[tokio::test(start_paused = true)]
+#[traced_test]
+async fn no_warning_for_zero_or_millisecond_cooldowns() {
+    // Test: Zero and whole-millisecond cooldowns are honoured exactly and do not warn
+    let _zero = Debouncer::new(Duration::ZERO, DebounceMode::Trailing);
+    let _millis = Debouncer::new(Duration::from_millis(1), DebounceMode::Trailing);
+    assert!(!logs_contain("below the timer resolution"));
+}
@@ -0,0 +1,261 @@
+// tests/value.rs
+
+use tokio_debouncer::DebounceMode;
+use tokio_debouncer::ValueDebouncer;
+use tokio::time::{self, Duration};
+
+#[tokio::test(start_paused = true)]
+async fn only_last_value_survives_burst() {
+    // Test: A burst of values coalesces to the last one written
+    let debounce = ValueDebouncer::new(Duration::from_secs(5), DebounceMode::Trailing);
+
+    debounce.trigger_with("a.txt");
+    debounce.trigger_with("b.txt");
+    debounce.trigger_with("c.txt");
+
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.take(), Some("c.txt"));
+    assert_eq!(guard.coalesced_count(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn take_clears_value() {
+    // Test: take() empties the slot so the next batch requires a fresh trigger
+    let debounce = ValueDebouncer::new(Duration::from_secs(5), DebounceMode::Leading);
+
+    debounce.trigger_with(1);
+    {
+        let mut guard = debounce.ready().await;
+        assert_eq!(guard.take(), Some(1));
+        assert_eq!(guard.take(), None);
+    }
+
+    let mut yielded = false;
+    tokio::select! {
+        _ = debounce.ready() => { yielded = true; }
+        _ = time::sleep(Duration::from_secs(10)) => {}
+    }
+    assert!(!yielded, "No batch without a fresh trigger");
+
+    debounce.trigger_with(2);
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.take(), Some(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn queue_drains_in_insertion_order() {
+    // Test: A queue debouncer yields every value of the burst, oldest first, then is empty
+    let debounce = ValueDebouncer::queue(Duration::from_secs(5), DebounceMode::Trailing);
+
+    debounce.trigger_with("a.txt");
+    debounce.trigger_with("b.txt");
+    debounce.trigger_with("a.txt");
+
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 3);
+    assert_eq!(guard.drain().collect::<Vec<_>>(), vec!["a.txt", "b.txt", "a.txt"]);
+    assert!(guard.take().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn drain_ready_ignores_cooldown() {
+    // Test: drain_ready() hands out the pending batch at once, and None when nothing is pending
+    let debounce = ValueDebouncer::queue(Duration::from_secs(5), DebounceMode::Trailing);
+    assert!(debounce.drain_ready().is_none());
+
+    debounce.trigger_with(1);
+    debounce.trigger_with(2);
+    let mut guard = debounce.drain_ready().expect("a batch is pending");
+    assert!(debounce.drain_ready().is_none(), "the batch is already claimed");
+    assert_eq!(guard.drain().collect::<Vec<_>>(), vec![1, 2]);
+    drop(guard);
+    assert!(!debounce.debouncer().is_triggered());
+    assert!(debounce.debouncer().drain_ready().is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn set_collects_distinct_values() {
+    // Test: A set debouncer merges overlapping ids and keeps distinct ones, per batch
+    use std::collections::HashSet;
+    let debounce = ValueDebouncer::set(Duration::from_secs(5), DebounceMode::Trailing);
+
+    for region in [3, 1, 3, 2, 1] {
+        debounce.trigger_with(region);
+    }
+    {
+        let mut guard = debounce.ready().await;
+        assert_eq!(guard.coalesced_count(), 5);
+        assert_eq!(guard.take(), HashSet::from([1, 2, 3]));
+    }
+
+    debounce.trigger_with(7);
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.take(), HashSet::from([7]));
+    assert!(guard.take().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn set_reports_keys_triggered_in_a_burst() {
+    // Test: Two keys triggered in one burst fire together and both appear in the guard
+    let debounce = ValueDebouncer::set(Duration::from_millis(100), DebounceMode::Trailing);
+    debounce.trigger_with("config");
+    debounce.trigger_with("routes");
+    debounce.trigger_with("config");
+
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.coalesced_count(), 3);
+    let mut keys: Vec<_> = guard.drain().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["config", "routes"]);
+    assert_eq!(guard.drain().count(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fold_sums_deltas() {
+    // Test: A summing reducer folds every delta of the burst, restarting from the initial value
+    let debounce = ValueDebouncer::fold(Duration::from_secs(5), DebounceMode::Trailing, 0i64, |total, delta| {
+        *total += delta
+    });
+
+    for delta in [5, -2, 10, 7] {
+        debounce.trigger_with(delta);
+    }
+    {
+        let mut guard = debounce.ready().await;
+        assert_eq!(guard.take(), 20);
+        assert_eq!(guard.take(), 0);
+    }
+
+    debounce.trigger_with(3);
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.take(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bounded_queue_drop_oldest_keeps_latest() {
+    // Test: DropOldest evicts from the front once the queue is full
+    use tokio_debouncer::{OverflowPolicy, QueueDebouncerBuilder};
+    let debounce = QueueDebouncerBuilder::new(Duration::from_secs(1), DebounceMode::Trailing)
+        .capacity(3)
+        .overflow(OverflowPolicy::DropOldest)
+        .build()
+        .unwrap();
+    for value in 1..=5 {
+        debounce.trigger_with(value);
+    }
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.drain().collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(guard.coalesced_count(), 5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bounded_queue_drop_newest_keeps_earliest() {
+    // Test: DropNewest discards values arriving after the queue is full
+    use tokio_debouncer::{OverflowPolicy, QueueDebouncerBuilder};
+    let debounce = QueueDebouncerBuilder::new(Duration::from_secs(1), DebounceMode::Trailing)
+        .capacity(3)
+        .overflow(OverflowPolicy::DropNewest)
+        .build()
+        .unwrap();
+    for value in 1..=5 {
+        debounce.trigger_with(value);
+    }
+    let mut guard = debounce.ready().await;
+    assert_eq!(guard.drain().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn bounded_queue_fire_now_skips_cooldown() {
+    // Test: FireNow forces the batch out as soon as the queue reaches capacity
+    use tokio_debouncer::{OverflowPolicy, QueueDebouncerBuilder};
+    let debounce = QueueDebouncerBuilder::new(Duration::from_secs(10), DebounceMode::Trailing)
+        .capacity(3)
+        .overflow(OverflowPolicy::FireNow)
+        .build()
+        .unwrap();
+    let start = time::Instant::now();
+    for value in 1..=3 {
+        debounce.trigger_with(value);
+    }
+    let mut guard = debounce.ready().await;
+    assert_eq!(time::Instant::now(), start);
+    assert_eq!(guard.drain().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn bounded_queue_rejects_zero_capacity() {
+    // Test: A capacity of zero is a build error
+    use tokio_debouncer::{BuildError, QueueDebouncerBuilder};
+    let result = QueueDebouncerBuilder::new(Duration::from_secs(1), DebounceMode::Trailing)
+        .capacity(0)
+        .build::<u32>();
+    assert!(matches!(result, Err(BuildError::ZeroCapacity)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn next_value_takes_payload_with_guard() {
+    // Test: next_value returns the last-written value and leaves the slot empty
+    let debounce = ValueDebouncer::new(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger_with(1);
+    debounce.trigger_with(2);
+    let (guard, value) = debounce.next_value().await;
+    assert_eq!(value, Some(2));
+    assert_eq!(guard.coalesced_count(), 2);
+    drop(guard);
+
+    // A bare trigger opens a batch with nothing in the slot
+    debounce.debouncer().trigger();
+    let (_guard, value) = debounce.next_value().await;
+    assert_eq!(value, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pipe_to_forwards_payloads() {
+    // Test: Each batch's payload is sent into the channel
+    let debounce = ValueDebouncer::queue(Duration::from_millis(100), DebounceMode::Trailing);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+    let _task = debounce.pipe_to(tx);
+
+    debounce.trigger_with(1);
+    debounce.trigger_with(2);
+    assert_eq!(rx.recv().await.unwrap(), [1, 2]);
+    debounce.trigger_with(3);
+    assert_eq!(rx.recv().await.unwrap(), [3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn value_merged_before_take_does_not_refire_empty() {
+    // Test: A value arriving while the guard is held but before take() goes out with the batch,
+    // and doesn't leave an empty batch behind
+    let debounce = ValueDebouncer::queue(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger_with(1);
+    {
+        let mut guard = debounce.ready().await;
+        debounce.trigger_with(2);
+        assert_eq!(guard.take(), [1, 2]);
+        assert_eq!(guard.coalesced_count(), 2);
+    }
+    assert!(!debounce.debouncer().is_triggered());
+
+    // A value arriving after take() still gets a batch of its own
+    debounce.trigger_with(3);
+    {
+        let mut guard = debounce.ready().await;
+        assert_eq!(guard.take(), [3]);
+        debounce.trigger_with(4);
+    }
+    let (_guard, value) = debounce.next_value().await;
+    assert_eq!(value, [4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_with_after_close_drops_value() {
+    // Test: Once closed, values are no longer merged into the batch still pending
+    let debounce = ValueDebouncer::queue(Duration::from_secs(1), DebounceMode::Trailing);
+    debounce.trigger_with(1);
+    debounce.debouncer().close();
+    debounce.trigger_with(2);
+    let (guard, value) = debounce.next_value().await;
+    assert_eq!(value, [1]);
+    assert_eq!(guard.coalesced_count(), 1);
+}